@@ -1,31 +1,379 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use tokio::sync::mpsc;
 
 use crate::aws::AwsClient;
+use crate::config::Config;
+use crate::theme::Theme;
 
 #[derive(Debug, Clone)]
 pub enum AppEvent {
-    ClustersLoaded(Vec<Cluster>),
-    ServicesLoaded(Vec<Service>),
-    TasksLoaded(Vec<Task>),
-    ContainersLoaded(Vec<Container>),
-    Ec2InstancesLoaded(Vec<Ec2Instance>),
-    DeploymentTriggered(String),
-    Error(String),
+    /// `op_id` is the operation `App::start_operation` returned when the
+    /// discovery/fetch that produced this event was kicked off, so the
+    /// handler knows which footer spinner entry to clear.
+    RegionsLoaded { regions: Vec<Region>, op_id: u64 },
+    ClustersLoaded { clusters: Vec<Cluster>, op_id: u64 },
+    ServicesLoaded { services: Vec<Service>, op_id: u64 },
+    TasksLoaded { tasks: Vec<Task>, op_id: u64 },
+    ContainersLoaded { containers: Vec<Container>, op_id: u64 },
+    Ec2InstancesLoaded { instances: Vec<Ec2Instance>, op_id: u64 },
+    RdsClustersLoaded { clusters: Vec<RdsCluster>, op_id: u64 },
+    RdsInstancesLoaded { instances: Vec<RdsInstance>, op_id: u64 },
+    DeploymentTriggered { service: String, op_id: u64 },
+    /// Emitted by the poller `App::force_deployment` spawns, roughly every
+    /// `ROLLOUT_POLL_INTERVAL`, until the rollout reaches a terminal state.
+    DeploymentProgress {
+        service: String,
+        rollout_state: RolloutState,
+        running: i32,
+        desired: i32,
+        pending: i32,
+        op_id: u64,
+    },
+    /// Emitted once the start/stop/reboot/terminate call made by
+    /// `App::invoke_lifecycle_action` succeeds, before the reconcile poll
+    /// begins.
+    LifecycleActionTriggered { resource_name: String, op_id: u64 },
+    /// Emitted by the poller `App::invoke_lifecycle_action` spawns, roughly
+    /// every `LIFECYCLE_POLL_INTERVAL`, until the resource reaches its
+    /// requested terminal state or the poll times out.
+    LifecycleStateChanged {
+        resource_name: String,
+        state: String,
+        terminal: bool,
+        op_id: u64,
+    },
+    /// `op_id` is `Some` when the failure belongs to a tracked operation -
+    /// its spinner entry turns into a red "failed" one instead of just
+    /// disappearing - and `None` for errors with no operation of their own.
+    Error { message: String, op_id: Option<u64> },
+    /// One line tailed from a container's log stream by the task
+    /// `App::open_log_viewer` spawns.
+    LogLine(String),
+    /// The log-tailing task stopped on its own (a fetch error, usually) -
+    /// distinct from the user closing the popup, which just aborts the task.
+    LogStreamEnded,
+    /// Fired by the background ticker spawned in `App::new`. A no-op while
+    /// `auto_refresh_enabled` is false.
+    RefreshTick,
+}
+
+/// How often the background ticker re-runs the loader for whatever
+/// navigation level is currently on screen.
+const AUTO_REFRESH_INTERVAL: std::time::Duration = std::time::Duration::from_secs(15);
+
+/// How often a force-deployed rollout's status is re-polled.
+const ROLLOUT_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(5);
+/// How long to keep polling a rollout before giving up and reporting a
+/// timeout rather than guessing at a terminal state we never observed.
+const ROLLOUT_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(300);
+
+/// How often a start/stop/reboot/terminate lifecycle action's reconcile
+/// loop re-polls the instance's state.
+const LIFECYCLE_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(5);
+/// How long to keep polling a lifecycle action before giving up and
+/// reporting a timeout rather than guessing at a terminal state we never
+/// observed.
+const LIFECYCLE_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(300);
+
+/// How often the log viewer re-polls `FilterLogEvents` for new lines.
+const LOG_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(3);
+/// How far back the log viewer starts tailing from when it's first opened.
+const LOG_LOOKBACK: std::time::Duration = std::time::Duration::from_secs(300);
+
+/// Frames of the footer's activity spinner, advanced one per event-loop
+/// tick by `App::advance_spinner`.
+const SPINNER_FRAMES: &[char] = &['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+/// How long a failed operation stays visible in the footer before
+/// `App::prune_expired_operations` drops it.
+const FAILED_OPERATION_TTL: std::time::Duration = std::time::Duration::from_secs(5);
+/// Lines scrolled per PageUp/PageDown press in the info popup.
+const INFO_POPUP_PAGE_SIZE: u16 = 10;
+
+/// Tail a container's CloudWatch log stream, sending each new line as an
+/// `AppEvent::LogLine` until the popup is closed (the caller aborts this
+/// task's `JoinHandle`) or a fetch fails, in which case an `AppEvent::Error`
+/// and a final `AppEvent::LogStreamEnded` are sent before returning.
+async fn tail_log_stream(
+    client: crate::aws::AwsClient,
+    tx: mpsc::Sender<AppEvent>,
+    region: String,
+    log_group: String,
+    log_stream: String,
+) {
+    let mut start_time_ms = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis() as i64)
+        .unwrap_or(0)
+        - LOG_LOOKBACK.as_millis() as i64;
+
+    let mut interval = tokio::time::interval(LOG_POLL_INTERVAL);
+
+    loop {
+        interval.tick().await;
+
+        match client
+            .filter_log_events(&region, &log_group, &log_stream, start_time_ms)
+            .await
+        {
+            Ok((lines, next_start_time_ms)) => {
+                start_time_ms = next_start_time_ms;
+                for line in lines {
+                    if tx.send(AppEvent::LogLine(line)).await.is_err() {
+                        return;
+                    }
+                }
+            }
+            Err(e) => {
+                let _ = tx
+                    .send(AppEvent::Error {
+                        message: format!("Log stream error: {}", e),
+                        op_id: None,
+                    })
+                    .await;
+                let _ = tx.send(AppEvent::LogStreamEnded).await;
+                return;
+            }
+        }
+    }
+}
+
+/// Poll a freshly-triggered deployment until it reaches a terminal
+/// [`RolloutState`] or [`ROLLOUT_TIMEOUT`] elapses, sending a
+/// [`AppEvent::DeploymentProgress`] after every poll. Spawned as its own
+/// task by [`App::force_deployment`] since `App` can't be borrowed across
+/// the wait.
+async fn poll_rollout(
+    client: crate::aws::AwsClient,
+    tx: mpsc::Sender<AppEvent>,
+    region_name: String,
+    cluster_arn: String,
+    service_name: String,
+    deployment_id: String,
+    op_id: u64,
+) {
+    let deadline = std::time::Instant::now() + ROLLOUT_TIMEOUT;
+    let mut interval = tokio::time::interval(ROLLOUT_POLL_INTERVAL);
+
+    loop {
+        interval.tick().await;
+
+        if std::time::Instant::now() >= deadline {
+            let _ = tx
+                .send(AppEvent::Error {
+                    message: format!(
+                        "Timed out waiting for {}'s deployment to finish rolling out",
+                        service_name
+                    ),
+                    op_id: Some(op_id),
+                })
+                .await;
+            break;
+        }
+
+        match client
+            .describe_service_deployment(&region_name, &cluster_arn, &service_name, &deployment_id)
+            .await
+        {
+            Ok(status) => {
+                let terminal = !matches!(status.rollout_state, RolloutState::InProgress);
+                let _ = tx
+                    .send(AppEvent::DeploymentProgress {
+                        service: service_name.clone(),
+                        rollout_state: status.rollout_state,
+                        running: status.running,
+                        desired: status.desired,
+                        pending: status.pending,
+                        op_id,
+                    })
+                    .await;
+                if terminal {
+                    break;
+                }
+            }
+            Err(e) => {
+                let _ = tx
+                    .send(AppEvent::Error {
+                        message: format!(
+                            "Failed to check deployment status for {}: {}",
+                            service_name, e
+                        ),
+                        op_id: Some(op_id),
+                    })
+                    .await;
+                break;
+            }
+        }
+    }
+}
+
+/// Intermediate EC2 instance states a lifecycle action's reconcile loop
+/// should keep waiting through rather than treat as done.
+const EC2_TRANSIENT_STATES: &[&str] = &["pending", "stopping", "shutting-down"];
+
+/// Poll an EC2 instance's state until it reaches `target_state` or
+/// `LIFECYCLE_TIMEOUT` elapses, sending an `AppEvent::LifecycleStateChanged`
+/// after every poll. Spawned as its own task by
+/// `App::invoke_lifecycle_action` since `App` can't be borrowed across the
+/// wait.
+async fn poll_ec2_lifecycle(
+    client: crate::aws::AwsClient,
+    tx: mpsc::Sender<AppEvent>,
+    region: String,
+    instance_id: String,
+    resource_name: String,
+    target_state: &'static str,
+    op_id: u64,
+) {
+    let deadline = std::time::Instant::now() + LIFECYCLE_TIMEOUT;
+    let mut interval = tokio::time::interval(LIFECYCLE_POLL_INTERVAL);
+
+    loop {
+        interval.tick().await;
+
+        if std::time::Instant::now() >= deadline {
+            let _ = tx
+                .send(AppEvent::Error {
+                    message: format!(
+                        "Timed out waiting for {} to reach '{}'",
+                        resource_name, target_state
+                    ),
+                    op_id: Some(op_id),
+                })
+                .await;
+            break;
+        }
+
+        match client.describe_ec2_instance_state(&region, &instance_id).await {
+            Ok(state) => {
+                let terminal = state == target_state || !EC2_TRANSIENT_STATES.contains(&state.as_str());
+                let _ = tx
+                    .send(AppEvent::LifecycleStateChanged {
+                        resource_name: resource_name.clone(),
+                        state,
+                        terminal,
+                        op_id,
+                    })
+                    .await;
+                if terminal {
+                    break;
+                }
+            }
+            Err(e) => {
+                // A terminated instance eventually stops showing up in
+                // describe_instances at all - that's success, not a
+                // failure to report.
+                if target_state == "terminated" {
+                    let _ = tx
+                        .send(AppEvent::LifecycleStateChanged {
+                            resource_name: resource_name.clone(),
+                            state: "terminated".to_string(),
+                            terminal: true,
+                            op_id,
+                        })
+                        .await;
+                } else {
+                    let _ = tx
+                        .send(AppEvent::Error {
+                            message: format!("Failed to check state for {}: {}", resource_name, e),
+                            op_id: Some(op_id),
+                        })
+                        .await;
+                }
+                break;
+            }
+        }
+    }
+}
+
+/// Intermediate RDS instance statuses a lifecycle action's reconcile loop
+/// should keep waiting through rather than treat as done.
+const RDS_TRANSIENT_STATUSES: &[&str] =
+    &["starting", "stopping", "rebooting", "modifying", "backing-up", "configuring-enhanced-monitoring"];
+
+/// Poll an RDS instance's status until it reaches `target_status` or
+/// `LIFECYCLE_TIMEOUT` elapses, sending an `AppEvent::LifecycleStateChanged`
+/// after every poll. Spawned as its own task by
+/// `App::invoke_lifecycle_action` since `App` can't be borrowed across the
+/// wait.
+async fn poll_rds_lifecycle(
+    client: crate::aws::AwsClient,
+    tx: mpsc::Sender<AppEvent>,
+    region: String,
+    identifier: String,
+    resource_name: String,
+    target_status: &'static str,
+    op_id: u64,
+) {
+    let deadline = std::time::Instant::now() + LIFECYCLE_TIMEOUT;
+    let mut interval = tokio::time::interval(LIFECYCLE_POLL_INTERVAL);
+
+    loop {
+        interval.tick().await;
+
+        if std::time::Instant::now() >= deadline {
+            let _ = tx
+                .send(AppEvent::Error {
+                    message: format!(
+                        "Timed out waiting for {} to reach '{}'",
+                        resource_name, target_status
+                    ),
+                    op_id: Some(op_id),
+                })
+                .await;
+            break;
+        }
+
+        match client.describe_rds_instance_status(&region, &identifier).await {
+            Ok(status) => {
+                let terminal = status == target_status || !RDS_TRANSIENT_STATUSES.contains(&status.as_str());
+                let _ = tx
+                    .send(AppEvent::LifecycleStateChanged {
+                        resource_name: resource_name.clone(),
+                        state: status,
+                        terminal,
+                        op_id,
+                    })
+                    .await;
+                if terminal {
+                    break;
+                }
+            }
+            Err(e) => {
+                let _ = tx
+                    .send(AppEvent::Error {
+                        message: format!("Failed to check status for {}: {}", resource_name, e),
+                        op_id: Some(op_id),
+                    })
+                    .await;
+                break;
+            }
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct Region {
     pub name: String,
+    /// The AWS partition this region belongs to (`aws`, `aws-cn`,
+    /// `aws-us-gov`), inferred by [`crate::aws::AwsClient::list_regions`]
+    /// from the region name prefix. `None` for the hard-coded fallback list
+    /// built before that discovery call has returned.
+    pub partition: Option<String>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Cluster {
     pub arn: String,
     pub name: String,
+    /// Region this cluster was discovered in. Always populated, but only
+    /// surfaced in the UI when browsing in "all regions" mode.
+    #[serde(default)]
+    pub region: String,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Service {
     #[allow(dead_code)]
     pub arn: String,
@@ -35,7 +383,7 @@ pub struct Service {
     pub running_count: i32,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Task {
     pub arn: String,
     pub task_id: String,
@@ -44,16 +392,28 @@ pub struct Task {
     pub memory: String,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Container {
     pub name: String,
     pub image: String,
     pub status: String,
-    #[allow(dead_code)]
     pub runtime_id: Option<String>,
 }
 
-#[derive(Debug, Clone)]
+/// A live SSM port-forwarding tunnel to an EC2 instance.
+///
+/// The child process is kept alive for as long as the tunnel should stay up;
+/// dropping the session without killing `child` would leak the `aws ssm`
+/// process, so callers must always go through [`App::stop_port_forward`] or
+/// [`App::stop_all_port_forward_sessions`].
+pub struct PortForwardSession {
+    pub instance_id: String,
+    pub remote_port: u16,
+    pub local_port: u16,
+    child: std::process::Child,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Ec2Instance {
     pub instance_id: String,
     pub name: String,
@@ -66,12 +426,317 @@ pub struct Ec2Instance {
     pub key_name: Option<String>,
     pub iam_instance_profile: Option<String>,
     pub ssm_managed: bool,
+    pub security_groups: Vec<SecurityGroupInfo>,
+    pub block_devices: Vec<BlockDevice>,
+    pub network_interfaces: Vec<NetworkInterfaceInfo>,
+    /// Region this instance was discovered in. Always populated, but only
+    /// surfaced in the UI when browsing in "all regions" mode.
+    #[serde(default)]
+    pub region: String,
+    /// All tags on the instance, including `Name` (already split out above).
+    /// Used to build the `tag_<key>_<value>` groups in the Ansible
+    /// dynamic-inventory export.
+    #[serde(default)]
+    pub tags: HashMap<String, String>,
+}
+
+/// One EBS volume attached to an instance, resolved via
+/// `describe_volumes` so size/type/encryption show up alongside the
+/// mapping `describe_instances` already gives us.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BlockDevice {
+    pub device_name: String,
+    pub volume_id: String,
+    pub size_gb: i32,
+    pub volume_type: String,
+    pub delete_on_termination: bool,
+    pub encrypted: bool,
+}
+
+/// One network interface (ENI) attached to an instance.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NetworkInterfaceInfo {
+    pub device_index: i32,
+    pub network_interface_id: String,
+    pub subnet_id: Option<String>,
+    pub private_ip: Option<String>,
+    pub public_ip: Option<String>,
+    pub mac_address: Option<String>,
+    pub security_group_ids: Vec<String>,
+}
+
+/// One inbound or outbound rule of a security group's IP permission set.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IpPermissionInfo {
+    pub protocol: String,
+    pub from_port: Option<i32>,
+    pub to_port: Option<i32>,
+    pub cidrs: Vec<String>,
+}
+
+/// A security group attached to an EC2 instance, resolved via
+/// `describe_security_groups` so the TUI can show network exposure directly.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SecurityGroupInfo {
+    pub group_id: String,
+    pub group_name: String,
+    pub vpc_id: Option<String>,
+    pub description: String,
+    pub inbound: Vec<IpPermissionInfo>,
+    pub outbound: Vec<IpPermissionInfo>,
+}
+
+/// An RDS cluster (Aurora), as surfaced by `AwsClient::list_rds_clusters`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RdsCluster {
+    pub arn: String,
+    pub identifier: String,
+    pub engine: String,
+    pub engine_version: String,
+    pub status: String,
+    pub endpoint: Option<String>,
+    pub reader_endpoint: Option<String>,
+    pub port: i32,
+    pub master_username: String,
+    pub database_name: Option<String>,
+    pub multi_az: bool,
+    pub storage_encrypted: bool,
+}
+
+/// A standalone RDS instance or a member instance of an RDS cluster, as
+/// surfaced by `AwsClient::list_rds_instances`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RdsInstance {
+    pub arn: String,
+    pub identifier: String,
+    pub cluster_identifier: Option<String>,
+    pub engine: String,
+    pub engine_version: String,
+    pub instance_class: String,
+    pub status: String,
+    pub endpoint: Option<String>,
+    pub port: i32,
+    pub availability_zone: String,
+    pub multi_az: bool,
+    pub storage_type: String,
+    pub allocated_storage: i32,
+}
+
+/// Extra identity the exporter has on hand beyond the selected resource
+/// itself - the region it was discovered in and, for ECS resources, the
+/// owning cluster's ARN - for [`RenderIac`] impls that need it.
+pub struct IacContext<'a> {
+    pub region: &'a str,
+    pub cluster_arn: Option<&'a str>,
+}
+
+/// Reverse-engineer infrastructure-as-code from an already-discovered
+/// resource, for the `'I'` export popup. Best-effort: fields the TUI's
+/// inventory doesn't track (e.g. an EC2 instance's AMI) are never guessed
+/// at: the Terraform output calls them out with a placeholder comment, and
+/// the CloudFormation output leaves those properties out.
+pub trait RenderIac {
+    fn render_terraform(&self, ctx: &IacContext) -> String;
+    fn render_cloudformation(&self, ctx: &IacContext) -> String;
+}
+
+/// Turn a resource name into a valid Terraform/CloudFormation identifier by
+/// replacing anything that isn't alphanumeric or `_` with `_`.
+fn iac_resource_id(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+impl RenderIac for Ec2Instance {
+    fn render_terraform(&self, _ctx: &IacContext) -> String {
+        let key_name = self
+            .key_name
+            .as_ref()
+            .map(|k| format!("  key_name              = \"{}\"\n", k))
+            .unwrap_or_default();
+        let iam_profile = self
+            .iam_instance_profile
+            .as_ref()
+            .map(|p| format!("  iam_instance_profile  = \"{}\"\n", p))
+            .unwrap_or_default();
+
+        format!(
+            "resource \"aws_instance\" \"{id}\" {{\n  \
+            instance_type         = \"{instance_type}\"\n  \
+            ami                   = \"\" # not tracked by ncaws - fill in the AMI this instance was launched from\n\
+            {key_name}{iam_profile}  \
+            tags = {{\n    Name = \"{name}\"\n  }}\n}}\n",
+            id = iac_resource_id(&self.name),
+            instance_type = self.instance_type,
+            key_name = key_name,
+            iam_profile = iam_profile,
+            name = self.name,
+        )
+    }
+
+    fn render_cloudformation(&self, _ctx: &IacContext) -> String {
+        let mut properties = serde_json::json!({
+            "InstanceType": self.instance_type,
+            "Tags": [{ "Key": "Name", "Value": self.name }],
+        });
+        if let Some(key_name) = &self.key_name {
+            properties["KeyName"] = serde_json::json!(key_name);
+        }
+        if let Some(profile) = &self.iam_instance_profile {
+            properties["IamInstanceProfile"] = serde_json::json!(profile);
+        }
+        let template = serde_json::json!({
+            "Resources": {
+                iac_resource_id(&self.name): {
+                    "Type": "AWS::EC2::Instance",
+                    "Properties": properties,
+                }
+            }
+        });
+        serde_json::to_string_pretty(&template).unwrap_or_default()
+    }
+}
+
+impl RenderIac for RdsInstance {
+    fn render_terraform(&self, _ctx: &IacContext) -> String {
+        format!(
+            "resource \"aws_db_instance\" \"{id}\" {{\n  \
+            identifier        = \"{identifier}\"\n  \
+            engine            = \"{engine}\"\n  \
+            engine_version    = \"{engine_version}\"\n  \
+            instance_class    = \"{instance_class}\"\n  \
+            allocated_storage = {allocated_storage}\n  \
+            storage_type      = \"{storage_type}\"\n  \
+            multi_az          = {multi_az}\n}}\n",
+            id = iac_resource_id(&self.identifier),
+            identifier = self.identifier,
+            engine = self.engine,
+            engine_version = self.engine_version,
+            instance_class = self.instance_class,
+            allocated_storage = self.allocated_storage,
+            storage_type = self.storage_type,
+            multi_az = self.multi_az,
+        )
+    }
+
+    fn render_cloudformation(&self, _ctx: &IacContext) -> String {
+        let template = serde_json::json!({
+            "Resources": {
+                iac_resource_id(&self.identifier): {
+                    "Type": "AWS::RDS::DBInstance",
+                    "Properties": {
+                        "DBInstanceIdentifier": self.identifier,
+                        "Engine": self.engine,
+                        "EngineVersion": self.engine_version,
+                        "DBInstanceClass": self.instance_class,
+                        "AllocatedStorage": self.allocated_storage.to_string(),
+                        "StorageType": self.storage_type,
+                        "MultiAZ": self.multi_az,
+                    }
+                }
+            }
+        });
+        serde_json::to_string_pretty(&template).unwrap_or_default()
+    }
+}
+
+impl RenderIac for Service {
+    fn render_terraform(&self, ctx: &IacContext) -> String {
+        let cluster_arn = ctx.cluster_arn.unwrap_or("<cluster ARN unknown>");
+        format!(
+            "resource \"aws_ecs_service\" \"{id}\" {{\n  \
+            name            = \"{name}\"\n  \
+            cluster         = \"{cluster_arn}\"\n  \
+            desired_count   = {desired_count}\n}}\n",
+            id = iac_resource_id(&self.name),
+            name = self.name,
+            cluster_arn = cluster_arn,
+            desired_count = self.desired_count,
+        )
+    }
+
+    fn render_cloudformation(&self, ctx: &IacContext) -> String {
+        let cluster_arn = ctx.cluster_arn.unwrap_or("<cluster ARN unknown>");
+        let template = serde_json::json!({
+            "Resources": {
+                iac_resource_id(&self.name): {
+                    "Type": "AWS::ECS::Service",
+                    "Properties": {
+                        "ServiceName": self.name,
+                        "Cluster": cluster_arn,
+                        "DesiredCount": self.desired_count,
+                    }
+                }
+            }
+        });
+        serde_json::to_string_pretty(&template).unwrap_or_default()
+    }
+}
+
+/// Which IaC dialect the `'I'` export popup is currently rendering.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IacFormat {
+    Terraform,
+    CloudFormation,
+}
+
+/// The lifecycle stage of an ECS service rollout, tracked by the poller
+/// `App::force_deployment` spawns after triggering one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RolloutState {
+    InProgress,
+    Completed,
+    Failed,
+    /// A newer deployment took over PRIMARY before ours reached a terminal
+    /// state - ECS's own automatic rollback.
+    RolledBack,
+}
+
+/// A point-in-time snapshot of a service's PRIMARY deployment, as returned
+/// by `AwsClient::describe_service_deployment`.
+#[derive(Debug, Clone)]
+pub struct DeploymentStatus {
+    pub rollout_state: RolloutState,
+    pub running: i32,
+    pub desired: i32,
+    pub pending: i32,
+}
+
+/// An ECS rollout being tracked since the last `force_deployment`, so the
+/// service list can render a live progress indicator instead of just a
+/// one-shot "triggered" message.
+#[derive(Debug, Clone)]
+pub struct ActiveRollout {
+    pub service_name: String,
+    pub status: DeploymentStatus,
+}
+
+/// Where a tracked [`Operation`] is in its lifecycle.
+#[derive(Debug, Clone)]
+pub enum OperationState {
+    InProgress,
+    Failed(String),
+}
+
+/// A single concurrent background operation (an AWS fetch, a deployment,
+/// ...), shown as a spinner entry in the footer. Replaces the old single
+/// `App::loading` flag so the content area never has to blank out while
+/// one or more of these are in flight.
+#[derive(Debug, Clone)]
+pub struct Operation {
+    id: u64,
+    pub name: String,
+    pub state: OperationState,
+    /// When this operation failed, so `App::prune_expired_operations` can
+    /// drop it once `FAILED_OPERATION_TTL` has passed.
+    failed_at: Option<std::time::Instant>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum NavigationLevel {
     Region,
-    ServiceType,  // Choose between ECS or EC2
+    ServiceType,  // Choose between ECS, EC2, or RDS
     // ECS path
     Cluster,
     Service,
@@ -79,12 +744,53 @@ pub enum NavigationLevel {
     Container,
     // EC2 path
     Ec2Instance,
+    // RDS path
+    RdsCluster,
+    RdsInstance,
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum ServiceType {
     ECS,
     EC2,
+    RDS,
+}
+
+/// One entry in the per-resource context menu, carrying both its label and
+/// enough identity for the event loop to dispatch it to the right handler.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ContextAction {
+    Exec,
+    ViewLogs,
+    Ssh,
+    StartInstance,
+    StopInstance,
+    RebootInstance,
+    TerminateInstance,
+    ForceDeploy,
+    Scale,
+    StartRdsInstance,
+    StopRdsInstance,
+    RebootRdsInstance,
+}
+
+impl ContextAction {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ContextAction::Exec => "Exec into container",
+            ContextAction::ViewLogs => "View logs",
+            ContextAction::Ssh => "SSH to instance",
+            ContextAction::StartInstance => "Start instance",
+            ContextAction::StopInstance => "Stop instance",
+            ContextAction::RebootInstance => "Reboot instance",
+            ContextAction::TerminateInstance => "Terminate instance",
+            ContextAction::ForceDeploy => "Force new deployment",
+            ContextAction::Scale => "Scale service",
+            ContextAction::StartRdsInstance => "Start DB instance",
+            ContextAction::StopRdsInstance => "Stop DB instance",
+            ContextAction::RebootRdsInstance => "Reboot instance",
+        }
+    }
 }
 
 pub struct NavigationState {
@@ -98,11 +804,123 @@ pub struct NavigationState {
     pub selected_container: Option<Container>,
     // EC2 fields
     pub selected_ec2_instance: Option<Ec2Instance>,
+    // RDS fields
+    pub selected_rds_cluster: Option<RdsCluster>,
+    pub selected_rds_instance: Option<RdsInstance>,
+    // Set while a `force_deployment` rollout is being polled; cleared when it
+    // reaches a terminal state or the user navigates away from the service.
+    pub active_rollout: Option<ActiveRollout>,
+    // Set by `App::enter_aggregate_mode` instead of picking a single region:
+    // subsequent fetches fan out across every region in `App::regions` and
+    // merge the results, each row annotated with its source region.
+    pub aggregate_regions: bool,
+}
+
+impl NavigationState {
+    fn new() -> Self {
+        Self {
+            level: NavigationLevel::Region,
+            service_type: None,
+            selected_region: None,
+            selected_cluster: None,
+            selected_service: None,
+            selected_task: None,
+            selected_container: None,
+            selected_ec2_instance: None,
+            selected_rds_cluster: None,
+            selected_rds_instance: None,
+            active_rollout: None,
+            aggregate_regions: false,
+        }
+    }
+}
+
+/// The titles of every open tab and which one is active, rendered as a strip
+/// between the header and the main content.
+pub struct TabsState {
+    pub titles: Vec<String>,
+    pub index: usize,
+}
+
+impl TabsState {
+    pub fn new(titles: Vec<String>) -> Self {
+        Self { titles, index: 0 }
+    }
+
+    pub fn next(&mut self) {
+        if !self.titles.is_empty() {
+            self.index = (self.index + 1) % self.titles.len();
+        }
+    }
+
+    pub fn previous(&mut self) {
+        if !self.titles.is_empty() {
+            self.index = if self.index == 0 {
+                self.titles.len() - 1
+            } else {
+                self.index - 1
+            };
+        }
+    }
+}
+
+/// One tab's navigation position and resource listings. Parked into
+/// `App::workspaces[tabs.index]` whenever a different tab becomes active, so
+/// `App`'s own fields always reflect "the current tab" and every other
+/// method (selection, loading, event handling, ...) can keep reading them
+/// directly instead of threading a tab index through.
+struct Workspace {
+    navigation: NavigationState,
+    regions: Vec<Region>,
+    service_types: Vec<ServiceType>,
+    clusters: Vec<Cluster>,
+    services: Vec<Service>,
+    tasks: Vec<Task>,
+    containers: Vec<Container>,
+    ec2_instances: Vec<Ec2Instance>,
+    rds_clusters: Vec<RdsCluster>,
+    rds_instances: Vec<RdsInstance>,
+    selected_index: usize,
+    operations: Vec<Operation>,
+}
+
+impl Workspace {
+    fn new() -> Self {
+        Self {
+            navigation: NavigationState::new(),
+            regions: App::default_regions(),
+            service_types: vec![ServiceType::ECS, ServiceType::EC2, ServiceType::RDS],
+            clusters: Vec::new(),
+            services: Vec::new(),
+            tasks: Vec::new(),
+            containers: Vec::new(),
+            ec2_instances: Vec::new(),
+            rds_clusters: Vec::new(),
+            rds_instances: Vec::new(),
+            selected_index: 0,
+            operations: Vec::new(),
+        }
+    }
 }
 
 pub struct App {
     pub aws_client: AwsClient,
     pub navigation: NavigationState,
+    // Other open tabs' navigation/listing state, parked here while they're
+    // not the active tab. `workspaces[tabs.index]` is stale (the active
+    // tab's real state lives in this struct's own fields above/below) and is
+    // overwritten with that state once the tab stops being active.
+    workspaces: Vec<Workspace>,
+    pub tabs: TabsState,
+    pub config: Config,
+    pub theme: Theme,
+    // Sorted names of `config.environments`, cycled with 'v'.
+    pub environment_names: Vec<String>,
+    pub current_environment: Option<String>,
+    // Named profiles from `~/.aws/config`, cycled with 'a'. Sessions launched
+    // while one is selected run under it via `aws-vault exec`.
+    pub aws_profiles: Vec<String>,
+    pub current_aws_profile: Option<String>,
     pub regions: Vec<Region>,
     pub service_types: Vec<ServiceType>,
     // ECS data
@@ -112,137 +930,948 @@ pub struct App {
     pub containers: Vec<Container>,
     // EC2 data
     pub ec2_instances: Vec<Ec2Instance>,
+    // RDS data
+    pub rds_clusters: Vec<RdsCluster>,
+    pub rds_instances: Vec<RdsInstance>,
+    // Active SSM port-forwarding tunnels, independent of navigation/selection.
+    pub port_forward_sessions: Vec<PortForwardSession>,
+    pub show_port_forward_panel: bool,
+    // Interactive shell (ECS Exec / SSH) embedded in the TUI as a PTY pane.
+    pub active_shell: Option<crate::pty::PtySession>,
     pub selected_index: usize,
-    pub loading: bool,
+    // Concurrent background operations (AWS fetches, deployments, ...),
+    // rendered as spinner entries in the footer instead of blanking the
+    // whole content area the way the old single `loading` flag did.
+    pub operations: Vec<Operation>,
+    next_operation_id: u64,
+    // Index into `SPINNER_FRAMES`, advanced once per event-loop tick so the
+    // footer spinner animates at a steady rate independent of redraw
+    // frequency.
+    spinner_frame: usize,
     pub error_message: Option<String>,
     pub status_message: String,
     pub show_info_popup: bool,
+    // Vertical scroll offset into the info popup's text, since the
+    // expanded EC2 panel (block devices, ENIs, security groups) can
+    // overflow the popup height.
+    pub info_popup_scroll: u16,
+    // Infrastructure-as-code export popup ('I'), showing a
+    // Terraform/CloudFormation snippet reverse-engineered from the
+    // currently selected resource.
+    pub show_iac_popup: bool,
+    pub iac_format: IacFormat,
+    // Context menu ('m' on the selected item), listing the actions valid
+    // for the current NavigationLevel.
+    pub show_context_menu: bool,
+    pub context_menu_actions: Vec<ContextAction>,
+    pub context_menu_index: usize,
+    // Fuzzy filter bar ('/'), narrowing whichever list is on screen down to
+    // the entries that match `filter_query` as a subsequence.
+    pub filter_mode: bool,
+    pub filter_query: String,
+    // Detail/preview pane ('P'), showing the selected resource's
+    // syntax-highlighted JSON to the right of its list.
+    pub show_preview_pane: bool,
+    preview_cache: std::collections::HashMap<String, Vec<crate::preview::HighlightedLine>>,
+    pub preview_scroll_offset: usize,
+    // Key of whichever resource the preview pane last rendered, so a new
+    // selection resets the scroll position instead of inheriting the old
+    // one.
+    last_preview_key: Option<String>,
+    // Whether the background ticker's `RefreshTick` events actually trigger
+    // a reload. Toggled with 'l'; the ticker itself keeps running either way.
+    pub auto_refresh_enabled: bool,
+    // Log viewer popup ('L' on a container), fed by `tail_log_stream`.
+    pub show_log_popup: bool,
+    pub log_lines: Vec<String>,
+    pub log_scroll_offset: usize,
+    // Whether the popup auto-scrolls to the newest line as it arrives.
+    pub log_follow: bool,
+    // Cancelled (aborted) when the popup closes so the polling task doesn't
+    // keep tailing logs nobody is looking at.
+    log_stream_handle: Option<tokio::task::JoinHandle<()>>,
+    // Reconcile loop spawned by a lifecycle action (start/stop/reboot/
+    // terminate on an EC2/RDS instance), aborted by
+    // `App::cancel_lifecycle_poll` so Esc can stop waiting on a resource
+    // nobody cares about the final state of anymore.
+    lifecycle_task_handle: Option<tokio::task::JoinHandle<()>>,
     quit: bool,
 }
 
+/// Escape `s` for safe interpolation inside single quotes in a shell
+/// command string, by ending the quoted section, emitting an escaped literal
+/// quote, and re-opening it (`'\''`). Used to build the `su -c '...'`
+/// command line without letting a `'` in the user-supplied command or
+/// username break out of the quoting.
+fn shell_single_quote(s: &str) -> String {
+    s.replace('\'', "'\\''")
+}
+
+/// Diff a freshly-loaded listing against what's currently on screen, keyed
+/// by a caller-supplied stable identifier (ARN, name, instance id, ...).
+/// Returns where the previously-selected key landed in the new list -
+/// clamped to the last row if it vanished, or `0` if nothing was selected
+/// yet - plus a short "+added/-removed/~changed" summary of what moved.
+fn diff_by_key<T: PartialEq, K: Eq + std::hash::Hash>(
+    old: &[T],
+    new: &[T],
+    key_fn: impl Fn(&T) -> K,
+    selected_key: Option<&K>,
+) -> (usize, String) {
+    let old_by_key: std::collections::HashMap<K, &T> =
+        old.iter().map(|item| (key_fn(item), item)).collect();
+    let new_keys: std::collections::HashSet<K> = new.iter().map(&key_fn).collect();
+
+    let added = new.iter().filter(|item| !old_by_key.contains_key(&key_fn(item))).count();
+    let removed = old_by_key.keys().filter(|k| !new_keys.contains(*k)).count();
+    let changed = new
+        .iter()
+        .filter(|item| {
+            old_by_key
+                .get(&key_fn(item))
+                .map(|prev| *prev != *item)
+                .unwrap_or(false)
+        })
+        .count();
+
+    let mut parts = Vec::new();
+    if added > 0 {
+        parts.push(format!("+{}", added));
+    }
+    if removed > 0 {
+        parts.push(format!("-{}", removed));
+    }
+    if changed > 0 {
+        parts.push(format!("~{}", changed));
+    }
+    let summary = if parts.is_empty() {
+        "unchanged".to_string()
+    } else {
+        parts.join("/")
+    };
+
+    let selected_index = match selected_key {
+        None => 0,
+        Some(key) => new
+            .iter()
+            .position(|item| &key_fn(item) == key)
+            .unwrap_or_else(|| new.len().saturating_sub(1)),
+    };
+
+    (selected_index, summary)
+}
+
 impl App {
-    pub async fn new() -> Result<Self> {
-        let aws_client = AwsClient::new().await?;
+    pub async fn new(offline: bool, tx: mpsc::Sender<AppEvent>) -> Result<Self> {
+        let aws_client = AwsClient::new().await?.with_offline(offline);
+        let config = Config::load()?;
+        let theme = Theme::load()?;
+
+        // Live auto-refresh: periodically ask the event loop to re-run
+        // whatever loader matches the current navigation level, so the TUI
+        // stays current without the user pressing 'r'. Gated by
+        // `auto_refresh_enabled` rather than stopping the timer, so toggling
+        // it back on doesn't need to re-spawn anything.
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(AUTO_REFRESH_INTERVAL);
+            loop {
+                interval.tick().await;
+                if tx.send(AppEvent::RefreshTick).await.is_err() {
+                    break;
+                }
+            }
+        });
+
+        let mut environment_names: Vec<String> = config.environments.keys().cloned().collect();
+        environment_names.sort();
+        let current_environment = environment_names.first().cloned();
+
+        let aws_profiles = crate::config::list_aws_profiles().unwrap_or_default();
 
-        let regions = vec![
-            Region { name: "us-east-1".to_string() },
-            Region { name: "us-west-2".to_string() },
-            Region { name: "eu-west-1".to_string() },
-            Region { name: "ap-southeast-1".to_string() },
-            Region { name: "ap-northeast-1".to_string() },
-        ];
+        // Populated immediately so the region list isn't empty on the first
+        // frame; `AppEvent::RegionsLoaded` replaces it with the account's
+        // actually-enabled regions once `AwsClient::list_regions` returns
+        // (or leaves it in place if that call is denied).
+        let regions = Self::default_regions();
+
+        // Pre-select the default environment's region in the region list.
+        let selected_index = current_environment
+            .as_ref()
+            .and_then(|name| config.environments.get(name))
+            .and_then(|env| regions.iter().position(|r| r.name == env.aws_region))
+            .unwrap_or(0);
 
         Ok(Self {
             aws_client,
-            navigation: NavigationState {
-                level: NavigationLevel::Region,
-                service_type: None,
-                selected_region: None,
-                selected_cluster: None,
-                selected_service: None,
-                selected_task: None,
-                selected_container: None,
-                selected_ec2_instance: None,
-            },
+            navigation: NavigationState::new(),
+            // The first tab's real state lives in the fields above/below;
+            // this placeholder is overwritten with that state if the user
+            // later switches away from the first tab.
+            workspaces: vec![Workspace::new()],
+            tabs: TabsState::new(vec!["Tab 1".to_string()]),
+            config,
+            theme,
+            environment_names,
+            current_environment,
+            aws_profiles,
+            current_aws_profile: None,
             regions,
-            service_types: vec![ServiceType::ECS, ServiceType::EC2],
+            service_types: vec![ServiceType::ECS, ServiceType::EC2, ServiceType::RDS],
             clusters: Vec::new(),
             services: Vec::new(),
             tasks: Vec::new(),
             containers: Vec::new(),
             ec2_instances: Vec::new(),
-            selected_index: 0,
-            loading: false,
+            rds_clusters: Vec::new(),
+            rds_instances: Vec::new(),
+            port_forward_sessions: Vec::new(),
+            show_port_forward_panel: false,
+            active_shell: None,
+            selected_index,
+            operations: Vec::new(),
+            next_operation_id: 0,
+            spinner_frame: 0,
             error_message: None,
-            status_message: "Select a region to begin".to_string(),
+            status_message: "Discovering enabled regions...".to_string(),
             show_info_popup: false,
+            info_popup_scroll: 0,
+            show_iac_popup: false,
+            iac_format: IacFormat::Terraform,
+            show_context_menu: false,
+            context_menu_actions: Vec::new(),
+            context_menu_index: 0,
+            filter_mode: false,
+            filter_query: String::new(),
+            show_preview_pane: false,
+            preview_cache: std::collections::HashMap::new(),
+            preview_scroll_offset: 0,
+            last_preview_key: None,
+            auto_refresh_enabled: true,
+            show_log_popup: false,
+            log_lines: Vec::new(),
+            log_scroll_offset: 0,
+            log_follow: true,
+            log_stream_handle: None,
+            lifecycle_task_handle: None,
             quit: false,
         })
     }
 
-    pub fn toggle_info_popup(&mut self) {
-        self.show_info_popup = !self.show_info_popup;
+    /// Start tracking a background operation, shown as a spinner entry in
+    /// the footer until `finish_operation`/`fail_operation` resolves it.
+    /// Returns the id to pass to those.
+    pub fn start_operation(&mut self, name: impl Into<String>) -> u64 {
+        let id = self.next_operation_id;
+        self.next_operation_id += 1;
+        self.operations.push(Operation {
+            id,
+            name: name.into(),
+            state: OperationState::InProgress,
+            failed_at: None,
+        });
+        id
     }
 
-    pub fn close_info_popup(&mut self) {
-        self.show_info_popup = false;
+    /// Drop a completed operation's spinner entry. A no-op if it's already
+    /// gone, e.g. its tab was closed while the fetch was in flight.
+    pub fn finish_operation(&mut self, id: u64) {
+        self.operations.retain(|op| op.id != id);
     }
 
-    pub fn current_items_count(&self) -> usize {
-        match self.navigation.level {
-            NavigationLevel::Region => self.regions.len(),
-            NavigationLevel::ServiceType => self.service_types.len(),
-            NavigationLevel::Cluster => self.clusters.len(),
-            NavigationLevel::Service => self.services.len(),
-            NavigationLevel::Task => self.tasks.len(),
-            NavigationLevel::Container => self.containers.len(),
-            NavigationLevel::Ec2Instance => self.ec2_instances.len(),
+    /// Turn an operation's spinner entry red instead of clearing it, so the
+    /// error stays visible until `prune_expired_operations` times it out.
+    pub fn fail_operation(&mut self, id: u64, error: String) {
+        if let Some(op) = self.operations.iter_mut().find(|op| op.id == id) {
+            op.state = OperationState::Failed(error);
+            op.failed_at = Some(std::time::Instant::now());
         }
     }
 
-    pub fn next_item(&mut self) {
-        let count = self.current_items_count();
-        if count > 0 {
-            self.selected_index = (self.selected_index + 1) % count;
+    /// Drop failed operations whose `FAILED_OPERATION_TTL` has elapsed.
+    /// Called once per event-loop tick.
+    pub fn prune_expired_operations(&mut self) {
+        self.operations.retain(|op| match (&op.state, op.failed_at) {
+            (OperationState::Failed(_), Some(at)) => at.elapsed() < FAILED_OPERATION_TTL,
+            _ => true,
+        });
+    }
+
+    /// Advance the footer spinner by one frame. Called once per event-loop
+    /// tick so its speed tracks wall-clock time rather than redraw rate.
+    pub fn advance_spinner(&mut self) {
+        self.spinner_frame = (self.spinner_frame + 1) % SPINNER_FRAMES.len();
+    }
+
+    /// The spinner glyph to render next to each in-progress operation.
+    pub fn spinner_char(&self) -> char {
+        SPINNER_FRAMES[self.spinner_frame]
+    }
+
+    pub fn toggle_auto_refresh(&mut self) {
+        self.auto_refresh_enabled = !self.auto_refresh_enabled;
+        self.status_message = if self.auto_refresh_enabled {
+            "Live auto-refresh resumed".to_string()
+        } else {
+            "Live auto-refresh paused".to_string()
+        };
+    }
+
+    /// Pull the active tab's navigation/listing fields out of `self` into a
+    /// `Workspace`, leaving fresh/empty values behind. Used to park the
+    /// active tab before switching away from it.
+    fn extract_live_workspace(&mut self) -> Workspace {
+        Workspace {
+            navigation: std::mem::replace(&mut self.navigation, NavigationState::new()),
+            regions: std::mem::take(&mut self.regions),
+            service_types: std::mem::take(&mut self.service_types),
+            clusters: std::mem::take(&mut self.clusters),
+            services: std::mem::take(&mut self.services),
+            tasks: std::mem::take(&mut self.tasks),
+            containers: std::mem::take(&mut self.containers),
+            ec2_instances: std::mem::take(&mut self.ec2_instances),
+            rds_clusters: std::mem::take(&mut self.rds_clusters),
+            rds_instances: std::mem::take(&mut self.rds_instances),
+            selected_index: std::mem::take(&mut self.selected_index),
+            operations: std::mem::take(&mut self.operations),
         }
     }
 
-    pub fn previous_item(&mut self) {
-        let count = self.current_items_count();
-        if count > 0 {
-            if self.selected_index > 0 {
-                self.selected_index -= 1;
-            } else {
-                self.selected_index = count - 1;
+    /// The inverse of `extract_live_workspace`: make `workspace` the active
+    /// tab's navigation/listing state.
+    fn apply_workspace(&mut self, workspace: Workspace) {
+        self.navigation = workspace.navigation;
+        self.regions = workspace.regions;
+        self.service_types = workspace.service_types;
+        self.clusters = workspace.clusters;
+        self.services = workspace.services;
+        self.tasks = workspace.tasks;
+        self.containers = workspace.containers;
+        self.ec2_instances = workspace.ec2_instances;
+        self.rds_clusters = workspace.rds_clusters;
+        self.rds_instances = workspace.rds_instances;
+        self.selected_index = workspace.selected_index;
+        self.operations = workspace.operations;
+    }
+
+    /// Park the active tab's state at `self.workspaces[self.tabs.index]`
+    /// before switching away from it.
+    fn park_active_workspace(&mut self) {
+        let index = self.tabs.index;
+        self.workspaces[index] = self.extract_live_workspace();
+    }
+
+    /// Switch to the next open tab, wrapping around past the last one.
+    pub fn next_tab(&mut self) {
+        if self.tabs.titles.len() <= 1 {
+            return;
+        }
+        self.park_active_workspace();
+        self.tabs.next();
+        let incoming = std::mem::replace(&mut self.workspaces[self.tabs.index], Workspace::new());
+        self.apply_workspace(incoming);
+    }
+
+    /// Switch to the previous open tab, wrapping around past the first one.
+    pub fn previous_tab(&mut self) {
+        if self.tabs.titles.len() <= 1 {
+            return;
+        }
+        self.park_active_workspace();
+        self.tabs.previous();
+        let incoming = std::mem::replace(&mut self.workspaces[self.tabs.index], Workspace::new());
+        self.apply_workspace(incoming);
+    }
+
+    /// Open a new tab at `Region` level and switch to it, leaving every
+    /// other open tab exactly where it was.
+    pub fn open_tab(&mut self) {
+        self.park_active_workspace();
+        self.tabs.titles.push(format!("Tab {}", self.tabs.titles.len() + 1));
+        self.workspaces.push(Workspace::new());
+        self.tabs.index = self.workspaces.len() - 1;
+        self.apply_workspace(Workspace::new());
+        self.status_message = format!("Opened {}", self.tabs.titles[self.tabs.index]);
+    }
+
+    /// Close the active tab and switch to the one that takes its place
+    /// (clamped to the last tab if it was the rightmost). Refuses to close
+    /// the last remaining tab.
+    pub fn close_tab(&mut self) {
+        if self.workspaces.len() <= 1 {
+            self.status_message = "Can't close the only tab".to_string();
+            return;
+        }
+
+        let closing = self.tabs.index;
+        self.tabs.titles.remove(closing);
+        self.workspaces.remove(closing);
+        self.tabs.index = closing.min(self.workspaces.len() - 1);
+
+        let incoming = std::mem::replace(&mut self.workspaces[self.tabs.index], Workspace::new());
+        self.apply_workspace(incoming);
+        self.status_message = "Closed tab".to_string();
+    }
+
+    /// The region list shown before `AwsClient::list_regions` has returned,
+    /// and the fallback kept in place if that `DescribeRegions` call is
+    /// denied. Partition is left unset since these are assumed, not
+    /// discovered.
+    fn default_regions() -> Vec<Region> {
+        [
+            "us-east-1",
+            "us-west-2",
+            "eu-west-1",
+            "ap-southeast-1",
+            "ap-northeast-1",
+        ]
+        .into_iter()
+        .map(|name| Region { name: name.to_string(), partition: None })
+        .collect()
+    }
+
+    /// Switch to the next configured environment, re-pointing the region
+    /// list cursor at its `aws_region`. The user still has to re-select the
+    /// region (and anything below it) to actually load data against it.
+    pub fn cycle_environment(&mut self) {
+        if self.environment_names.is_empty() {
+            return;
+        }
+
+        let next_index = self
+            .current_environment
+            .as_ref()
+            .and_then(|name| self.environment_names.iter().position(|n| n == name))
+            .map(|i| (i + 1) % self.environment_names.len())
+            .unwrap_or(0);
+
+        let next_name = self.environment_names[next_index].clone();
+        if let Some(region_name) = self
+            .config
+            .environments
+            .get(&next_name)
+            .map(|env| env.aws_region.clone())
+        {
+            if let Some(index) = self.regions.iter().position(|r| r.name == region_name) {
+                self.selected_index = index;
             }
         }
+
+        self.status_message = format!("Switched to environment '{}'", next_name);
+        self.current_environment = Some(next_name);
     }
 
-    pub async fn select_item(&mut self, tx: mpsc::Sender<AppEvent>) -> Result<()> {
-        match self.navigation.level {
-            NavigationLevel::Region => {
-                if let Some(region) = self.regions.get(self.selected_index) {
-                    self.navigation.selected_region = Some(region.clone());
-                    self.navigation.level = NavigationLevel::ServiceType;
-                    self.status_message = "Select service type".to_string();
-                    self.selected_index = 0;
-                }
+    fn current_environment_config(&self) -> Option<&crate::config::Environment> {
+        self.current_environment
+            .as_ref()
+            .and_then(|name| self.config.environments.get(name))
+    }
+
+    /// Cycle through the profiles declared in `~/.aws/config`, or clear the
+    /// selection (back to ambient credentials) once the list wraps around.
+    pub fn cycle_aws_profile(&mut self) {
+        if self.aws_profiles.is_empty() {
+            return;
+        }
+
+        let next_index = match &self.current_aws_profile {
+            Some(name) => self
+                .aws_profiles
+                .iter()
+                .position(|p| p == name)
+                .map(|i| i + 1),
+            None => Some(0),
+        };
+
+        match next_index {
+            Some(i) if i < self.aws_profiles.len() => {
+                let profile = self.aws_profiles[i].clone();
+                self.status_message = format!("Sessions will run under AWS profile '{}'", profile);
+                self.current_aws_profile = Some(profile);
             }
-            NavigationLevel::ServiceType => {
-                if let Some(service_type) = self.service_types.get(self.selected_index) {
-                    self.navigation.service_type = Some(service_type.clone());
+            _ => {
+                self.status_message = "Sessions will run under ambient AWS credentials".to_string();
+                self.current_aws_profile = None;
+            }
+        }
+    }
 
-                    match service_type {
-                        ServiceType::ECS => {
-                            self.loading = true;
-                            let region = self.navigation.selected_region.as_ref().unwrap().name.clone();
-                            self.status_message = format!("Loading ECS clusters in {}...", region);
+    /// Wrap a session's program/args in `aws-vault exec <profile> --` when a
+    /// profile is selected, so MFA-gated or assume-role credentials get
+    /// resolved into the child's environment without the user pre-exporting
+    /// them.
+    fn wrap_with_profile(&self, program: String, args: Vec<String>) -> (String, Vec<String>) {
+        match &self.current_aws_profile {
+            Some(profile) => {
+                let mut vault_args = vec!["exec".to_string(), profile.clone(), "--".to_string(), program];
+                vault_args.extend(args);
+                ("aws-vault".to_string(), vault_args)
+            }
+            None => (program, args),
+        }
+    }
+
+    pub fn toggle_info_popup(&mut self) {
+        self.show_info_popup = !self.show_info_popup;
+        self.info_popup_scroll = 0;
+    }
+
+    pub fn close_info_popup(&mut self) {
+        self.show_info_popup = false;
+        self.cancel_lifecycle_poll();
+    }
+
+    pub fn info_popup_scroll_down(&mut self) {
+        self.info_popup_scroll = self.info_popup_scroll.saturating_add(1);
+    }
+
+    pub fn info_popup_scroll_up(&mut self) {
+        self.info_popup_scroll = self.info_popup_scroll.saturating_sub(1);
+    }
+
+    pub fn info_popup_scroll_page_down(&mut self) {
+        self.info_popup_scroll = self.info_popup_scroll.saturating_add(INFO_POPUP_PAGE_SIZE);
+    }
+
+    pub fn info_popup_scroll_page_up(&mut self) {
+        self.info_popup_scroll = self.info_popup_scroll.saturating_sub(INFO_POPUP_PAGE_SIZE);
+    }
+
+    pub fn toggle_iac_popup(&mut self) {
+        self.show_iac_popup = !self.show_iac_popup;
+    }
+
+    pub fn close_iac_popup(&mut self) {
+        self.show_iac_popup = false;
+    }
+
+    /// Switch the `'I'` popup between Terraform and CloudFormation output.
+    pub fn cycle_iac_format(&mut self) {
+        self.iac_format = match self.iac_format {
+            IacFormat::Terraform => IacFormat::CloudFormation,
+            IacFormat::CloudFormation => IacFormat::Terraform,
+        };
+    }
+
+    fn iac_context(&self) -> IacContext {
+        IacContext {
+            region: self
+                .navigation
+                .selected_region
+                .as_ref()
+                .map(|r| r.name.as_str())
+                .unwrap_or(""),
+            cluster_arn: self.navigation.selected_cluster.as_ref().map(|c| c.arn.as_str()),
+        }
+    }
+
+    /// Terraform or CloudFormation (per `self.iac_format`) for the currently
+    /// selected resource, shown in the `'I'` popup. `None` at navigation
+    /// levels with nothing to export, mirroring `selected_resource_json`.
+    fn selected_resource_iac(&self) -> Option<String> {
+        let ctx = self.iac_context();
+        match self.navigation.level {
+            NavigationLevel::Service => self.services.get(self.selected_index).map(|s| match self.iac_format {
+                IacFormat::Terraform => s.render_terraform(&ctx),
+                IacFormat::CloudFormation => s.render_cloudformation(&ctx),
+            }),
+            NavigationLevel::Ec2Instance => {
+                self.ec2_instances.get(self.selected_index).map(|i| match self.iac_format {
+                    IacFormat::Terraform => i.render_terraform(&ctx),
+                    IacFormat::CloudFormation => i.render_cloudformation(&ctx),
+                })
+            }
+            NavigationLevel::RdsInstance => {
+                self.rds_instances.get(self.selected_index).map(|i| match self.iac_format {
+                    IacFormat::Terraform => i.render_terraform(&ctx),
+                    IacFormat::CloudFormation => i.render_cloudformation(&ctx),
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// The text the `'I'` popup shows: the generated snippet, or an
+    /// explanation of why there's nothing to generate at this level.
+    pub fn iac_popup_text(&self) -> String {
+        self.selected_resource_iac()
+            .unwrap_or_else(|| "No exportable resource selected at this level.".to_string())
+    }
+
+    fn iac_export_dir() -> Result<std::path::PathBuf> {
+        let home = std::env::var("HOME").context("HOME is not set")?;
+        Ok(std::path::PathBuf::from(home).join(".config").join("ncaws").join("exports"))
+    }
+
+    /// Write the snippet currently shown in the `'I'` popup to
+    /// `~/.config/ncaws/exports/`, named after the selected resource and
+    /// the active format.
+    pub fn write_iac_export(&mut self) -> Result<()> {
+        let Some(body) = self.selected_resource_iac() else {
+            self.status_message = "Nothing to export at this level".to_string();
+            return Ok(());
+        };
+        let Some(key) = self.selected_resource_key() else {
+            return Ok(());
+        };
+
+        let extension = match self.iac_format {
+            IacFormat::Terraform => "tf",
+            IacFormat::CloudFormation => "json",
+        };
+        let dir = Self::iac_export_dir()?;
+        std::fs::create_dir_all(&dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+        let path = dir.join(format!("{}.{}", iac_resource_id(&key), extension));
+        std::fs::write(&path, body).with_context(|| format!("Failed to write {}", path.display()))?;
+        self.status_message = format!("Exported to {}", path.display());
+        Ok(())
+    }
+
+    /// Serialize the currently loaded EC2 instances into an Ansible dynamic
+    /// inventory document - the same shape the conventional `ec2` external
+    /// inventory script produces, so `ansible-playbook -i` can consume it
+    /// directly. Groups by instance type, availability zone, tag, and
+    /// security group, with a `_meta.hostvars` block so Ansible doesn't need
+    /// to re-query each host for its variables.
+    pub fn write_ansible_inventory(&mut self) -> Result<()> {
+        if self.ec2_instances.is_empty() {
+            self.status_message = "No EC2 instances loaded to export".to_string();
+            return Ok(());
+        }
+
+        let mut groups: std::collections::HashMap<String, Vec<String>> = std::collections::HashMap::new();
+        let mut hostvars = serde_json::Map::new();
+
+        for instance in &self.ec2_instances {
+            let host = instance.instance_id.clone();
+
+            groups
+                .entry(format!("type_{}", iac_resource_id(&instance.instance_type)))
+                .or_default()
+                .push(host.clone());
+            groups
+                .entry(format!("az_{}", iac_resource_id(&instance.availability_zone)))
+                .or_default()
+                .push(host.clone());
+            for (key, value) in &instance.tags {
+                groups
+                    .entry(format!("tag_{}_{}", iac_resource_id(key), iac_resource_id(value)))
+                    .or_default()
+                    .push(host.clone());
+            }
+            for sg in &instance.security_groups {
+                groups
+                    .entry(format!("sg_{}", iac_resource_id(&sg.group_id)))
+                    .or_default()
+                    .push(host.clone());
+            }
+
+            let ansible_host = instance
+                .public_ip
+                .clone()
+                .or_else(|| instance.private_ip.clone())
+                .unwrap_or_default();
+            hostvars.insert(
+                host,
+                serde_json::json!({
+                    "ansible_host": ansible_host,
+                    "instance_type": instance.instance_type,
+                    "availability_zone": instance.availability_zone,
+                    "private_ip": instance.private_ip,
+                    "public_ip": instance.public_ip,
+                    "iam_instance_profile": instance.iam_instance_profile,
+                }),
+            );
+        }
+
+        let mut inventory = serde_json::Map::new();
+        for (group, hosts) in groups {
+            inventory.insert(group, serde_json::json!(hosts));
+        }
+        inventory.insert("_meta".to_string(), serde_json::json!({ "hostvars": hostvars }));
+
+        let body =
+            serde_json::to_string_pretty(&inventory).context("Failed to serialize Ansible inventory")?;
+
+        let dir = Self::iac_export_dir()?;
+        std::fs::create_dir_all(&dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+        let path = dir.join("ansible-inventory.json");
+        std::fs::write(&path, body).with_context(|| format!("Failed to write {}", path.display()))?;
+        self.status_message = format!("Exported Ansible inventory to {}", path.display());
+        Ok(())
+    }
+
+    pub fn current_items_count(&self) -> usize {
+        match self.navigation.level {
+            NavigationLevel::Region => self.regions.len(),
+            NavigationLevel::ServiceType => self.service_types.len(),
+            NavigationLevel::Cluster => self.clusters.len(),
+            NavigationLevel::Service => self.services.len(),
+            NavigationLevel::Task => self.tasks.len(),
+            NavigationLevel::Container => self.containers.len(),
+            NavigationLevel::Ec2Instance => self.ec2_instances.len(),
+            NavigationLevel::RdsCluster => self.rds_clusters.len(),
+            NavigationLevel::RdsInstance => self.rds_instances.len(),
+        }
+    }
+
+    /// Searchable label of each entry in the list at the current
+    /// `NavigationLevel`, in the same order as `current_items_count`. Used
+    /// both to fuzzy-filter the list and to bold matched characters when
+    /// rendering it.
+    fn current_item_labels(&self) -> Vec<String> {
+        match self.navigation.level {
+            NavigationLevel::Region => self.regions.iter().map(|r| r.name.clone()).collect(),
+            NavigationLevel::ServiceType => self
+                .service_types
+                .iter()
+                .map(|t| match t {
+                    ServiceType::ECS => "ECS".to_string(),
+                    ServiceType::EC2 => "EC2".to_string(),
+                    ServiceType::RDS => "RDS".to_string(),
+                })
+                .collect(),
+            NavigationLevel::Cluster => self.clusters.iter().map(|c| c.name.clone()).collect(),
+            NavigationLevel::Service => self.services.iter().map(|s| s.name.clone()).collect(),
+            NavigationLevel::Task => self.tasks.iter().map(|t| t.task_id.clone()).collect(),
+            NavigationLevel::Container => self.containers.iter().map(|c| c.name.clone()).collect(),
+            NavigationLevel::Ec2Instance => self.ec2_instances.iter().map(|i| i.name.clone()).collect(),
+            NavigationLevel::RdsCluster => {
+                self.rds_clusters.iter().map(|c| c.identifier.clone()).collect()
+            }
+            NavigationLevel::RdsInstance => {
+                self.rds_instances.iter().map(|i| i.identifier.clone()).collect()
+            }
+        }
+    }
+
+    /// Real indices (into whichever `Vec` backs the current `NavigationLevel`)
+    /// that survive the active filter, sorted by descending fuzzy score. An
+    /// empty `filter_query` is the identity filter: every index, in original
+    /// order.
+    pub fn filtered_indices(&self) -> Vec<usize> {
+        if self.filter_query.is_empty() {
+            return (0..self.current_items_count()).collect();
+        }
+
+        crate::fuzzy::filter_and_sort(&self.filter_query, &self.current_item_labels())
+            .into_iter()
+            .map(|(index, _score, _matched)| index)
+            .collect()
+    }
+
+    /// Char indices into `label` that matched the active filter, for
+    /// bolding in the rendered list. Empty when there's no active filter or
+    /// `label` doesn't match it.
+    pub fn filter_match_indices(&self, label: &str) -> Vec<usize> {
+        if self.filter_query.is_empty() {
+            return Vec::new();
+        }
+        crate::fuzzy::fuzzy_match(&self.filter_query, label)
+            .map(|m| m.matched_indices)
+            .unwrap_or_default()
+    }
+
+    pub fn enter_filter_mode(&mut self) {
+        self.filter_mode = true;
+    }
+
+    /// Stop editing the filter but keep it applied, so the list stays
+    /// narrowed until the user clears it or navigates away.
+    pub fn confirm_filter(&mut self) {
+        self.filter_mode = false;
+    }
+
+    pub fn clear_filter(&mut self) {
+        self.filter_mode = false;
+        self.filter_query.clear();
+    }
+
+    pub fn filter_push_char(&mut self, c: char) {
+        self.filter_query.push(c);
+        self.clamp_selection_to_filter();
+    }
+
+    pub fn filter_pop_char(&mut self) {
+        self.filter_query.pop();
+        self.clamp_selection_to_filter();
+    }
+
+    /// Move `selected_index` onto the filtered list if the current
+    /// selection just fell out of it.
+    fn clamp_selection_to_filter(&mut self) {
+        let indices = self.filtered_indices();
+        if !indices.is_empty() && !indices.contains(&self.selected_index) {
+            self.selected_index = indices[0];
+        }
+    }
+
+    pub fn next_item(&mut self) {
+        let indices = self.filtered_indices();
+        if let Some(pos) = indices.iter().position(|&i| i == self.selected_index) {
+            self.selected_index = indices[(pos + 1) % indices.len()];
+        } else if let Some(&first) = indices.first() {
+            self.selected_index = first;
+        }
+    }
+
+    pub fn previous_item(&mut self) {
+        let indices = self.filtered_indices();
+        if let Some(pos) = indices.iter().position(|&i| i == self.selected_index) {
+            self.selected_index = indices[(pos + indices.len() - 1) % indices.len()];
+        } else if let Some(&first) = indices.first() {
+            self.selected_index = first;
+        }
+    }
+
+    /// Skip picking a single region and fan the ECS cluster and EC2 instance
+    /// listings out across every region in `self.regions` instead, merging
+    /// the results annotated by region. RDS is not fanned out and reports an
+    /// error in this mode. A no-op anywhere but the region list.
+    pub fn enter_aggregate_mode(&mut self) {
+        if self.navigation.level != NavigationLevel::Region {
+            return;
+        }
+        self.navigation.selected_region = None;
+        self.navigation.aggregate_regions = true;
+        self.navigation.level = NavigationLevel::ServiceType;
+        self.status_message = "Select service type (all regions)".to_string();
+        self.selected_index = 0;
+        self.clear_filter();
+    }
+
+    pub async fn select_item(&mut self, tx: mpsc::Sender<AppEvent>) -> Result<()> {
+        match self.navigation.level {
+            NavigationLevel::Region => {
+                if let Some(region) = self.regions.get(self.selected_index) {
+                    self.navigation.selected_region = Some(region.clone());
+                    self.navigation.level = NavigationLevel::ServiceType;
+                    self.status_message = "Select service type".to_string();
+                    self.selected_index = 0;
+                    self.clear_filter();
+                }
+            }
+            NavigationLevel::ServiceType => {
+                if let Some(service_type) = self.service_types.get(self.selected_index) {
+                    self.navigation.service_type = Some(service_type.clone());
+                    self.clear_filter();
+
+                    match service_type {
+                        ServiceType::ECS if self.navigation.aggregate_regions => {
+                            self.status_message = "Loading ECS clusters across all regions...".to_string();
+                            let op_id = self.start_operation("Loading clusters across all regions");
+
+                            let client = self.aws_client.clone();
+                            let regions: Vec<String> = self.regions.iter().map(|r| r.name.clone()).collect();
+                            tokio::spawn(async move {
+                                let (found, errors) = client.list_all_clusters(&regions).await;
+                                let clusters: Vec<Cluster> = found.into_iter().map(|(_, c)| c).collect();
+                                let _ = tx.send(AppEvent::ClustersLoaded { clusters, op_id }).await;
+                                if !errors.is_empty() {
+                                    let _ = tx
+                                        .send(AppEvent::Error {
+                                            message: format!(
+                                                "{} region(s) failed to load clusters",
+                                                errors.len()
+                                            ),
+                                            op_id: None,
+                                        })
+                                        .await;
+                                }
+                            });
+                        }
+                        ServiceType::ECS => {
+                            let region = self.navigation.selected_region.as_ref().unwrap().name.clone();
+                            self.status_message = format!("Loading ECS clusters in {}...", region);
+                            let op_id = self.start_operation(format!("Loading clusters in {}", region));
 
                             let client = self.aws_client.clone();
                             tokio::spawn(async move {
                                 match client.list_clusters(&region).await {
                                     Ok(clusters) => {
-                                        let _ = tx.send(AppEvent::ClustersLoaded(clusters)).await;
+                                        let _ = tx.send(AppEvent::ClustersLoaded { clusters, op_id }).await;
                                     }
                                     Err(e) => {
-                                        let _ = tx.send(AppEvent::Error(format!("Failed to load clusters: {}", e))).await;
+                                        let _ = tx
+                                            .send(AppEvent::Error {
+                                                message: format!("Failed to load clusters: {}", e),
+                                                op_id: Some(op_id),
+                                            })
+                                            .await;
                                     }
                                 }
                             });
                         }
+                        ServiceType::EC2 if self.navigation.aggregate_regions => {
+                            self.status_message = "Loading EC2 instances across all regions...".to_string();
+                            let op_id = self.start_operation("Loading EC2 instances across all regions");
+
+                            let client = self.aws_client.clone();
+                            let regions: Vec<String> = self.regions.iter().map(|r| r.name.clone()).collect();
+                            tokio::spawn(async move {
+                                let (found, errors) = client.list_all_ec2_instances(&regions).await;
+                                let instances: Vec<Ec2Instance> = found.into_iter().map(|(_, i)| i).collect();
+                                let _ = tx.send(AppEvent::Ec2InstancesLoaded { instances, op_id }).await;
+                                if !errors.is_empty() {
+                                    let _ = tx
+                                        .send(AppEvent::Error {
+                                            message: format!(
+                                                "{} region(s) failed to load EC2 instances",
+                                                errors.len()
+                                            ),
+                                            op_id: None,
+                                        })
+                                        .await;
+                                }
+                            });
+                        }
                         ServiceType::EC2 => {
-                            self.loading = true;
                             let region = self.navigation.selected_region.as_ref().unwrap().name.clone();
                             self.status_message = format!("Loading EC2 instances in {}...", region);
+                            let op_id = self.start_operation(format!("Loading EC2 instances in {}", region));
 
                             let client = self.aws_client.clone();
+                            let filters = self
+                                .current_environment_config()
+                                .map(|env| env.filters.clone())
+                                .unwrap_or_default();
                             tokio::spawn(async move {
-                                match client.list_ec2_instances(&region).await {
+                                match client.list_ec2_instances_filtered(&region, &filters).await {
                                     Ok(instances) => {
-                                        let _ = tx.send(AppEvent::Ec2InstancesLoaded(instances)).await;
+                                        let _ = tx.send(AppEvent::Ec2InstancesLoaded { instances, op_id }).await;
+                                    }
+                                    Err(e) => {
+                                        let _ = tx
+                                            .send(AppEvent::Error {
+                                                message: format!("Failed to load EC2 instances: {}", e),
+                                                op_id: Some(op_id),
+                                            })
+                                            .await;
+                                    }
+                                }
+                            });
+                        }
+                        ServiceType::RDS if self.navigation.aggregate_regions => {
+                            self.error_message =
+                                Some("RDS isn't available in all-regions mode; pick a single region".to_string());
+                        }
+                        ServiceType::RDS => {
+                            let region = self.navigation.selected_region.as_ref().unwrap().name.clone();
+                            self.status_message = format!("Loading RDS clusters in {}...", region);
+                            let op_id = self.start_operation(format!("Loading RDS clusters in {}", region));
+
+                            let client = self.aws_client.clone();
+                            tokio::spawn(async move {
+                                match client.list_rds_clusters(&region).await {
+                                    Ok(clusters) => {
+                                        let _ = tx.send(AppEvent::RdsClustersLoaded { clusters, op_id }).await;
                                     }
                                     Err(e) => {
-                                        let _ = tx.send(AppEvent::Error(format!("Failed to load EC2 instances: {}", e))).await;
+                                        let _ = tx
+                                            .send(AppEvent::Error {
+                                                message: format!("Failed to load RDS clusters: {}", e),
+                                                op_id: Some(op_id),
+                                            })
+                                            .await;
                                     }
                                 }
                             });
@@ -252,9 +1881,21 @@ impl App {
             }
             NavigationLevel::Cluster => {
                 if let Some(cluster) = self.clusters.get(self.selected_index) {
+                    // In "all regions" mode no single region was picked up
+                    // front - pin one now from the chosen cluster's own
+                    // `region` field, since everything below this level is
+                    // scoped to wherever that cluster actually lives.
+                    if self.navigation.aggregate_regions {
+                        self.navigation.selected_region = Some(Region {
+                            name: cluster.region.clone(),
+                            partition: None,
+                        });
+                    }
                     self.navigation.selected_cluster = Some(cluster.clone());
-                    self.loading = true;
+                    self.navigation.active_rollout = None;
+                    self.clear_filter();
                     self.status_message = format!("Loading services in {}...", cluster.name);
+                    let op_id = self.start_operation(format!("Loading services in {}", cluster.name));
 
                     let client = self.aws_client.clone();
                     let region = self.navigation.selected_region.as_ref().unwrap().name.clone();
@@ -262,10 +1903,15 @@ impl App {
                     tokio::spawn(async move {
                         match client.list_services(&region, &cluster_arn).await {
                             Ok(services) => {
-                                let _ = tx.send(AppEvent::ServicesLoaded(services)).await;
+                                let _ = tx.send(AppEvent::ServicesLoaded { services, op_id }).await;
                             }
                             Err(e) => {
-                                let _ = tx.send(AppEvent::Error(format!("Failed to load services: {}", e))).await;
+                                let _ = tx
+                                    .send(AppEvent::Error {
+                                        message: format!("Failed to load services: {}", e),
+                                        op_id: Some(op_id),
+                                    })
+                                    .await;
                             }
                         }
                     });
@@ -274,8 +1920,9 @@ impl App {
             NavigationLevel::Service => {
                 if let Some(service) = self.services.get(self.selected_index) {
                     self.navigation.selected_service = Some(service.clone());
-                    self.loading = true;
+                    self.clear_filter();
                     self.status_message = format!("Loading tasks for {}...", service.name);
+                    let op_id = self.start_operation(format!("Loading tasks for {}", service.name));
 
                     let client = self.aws_client.clone();
                     let region = self.navigation.selected_region.as_ref().unwrap().name.clone();
@@ -284,10 +1931,15 @@ impl App {
                     tokio::spawn(async move {
                         match client.list_tasks(&region, &cluster_arn, &service_name).await {
                             Ok(tasks) => {
-                                let _ = tx.send(AppEvent::TasksLoaded(tasks)).await;
+                                let _ = tx.send(AppEvent::TasksLoaded { tasks, op_id }).await;
                             }
                             Err(e) => {
-                                let _ = tx.send(AppEvent::Error(format!("Failed to load tasks: {}", e))).await;
+                                let _ = tx
+                                    .send(AppEvent::Error {
+                                        message: format!("Failed to load tasks: {}", e),
+                                        op_id: Some(op_id),
+                                    })
+                                    .await;
                             }
                         }
                     });
@@ -296,8 +1948,9 @@ impl App {
             NavigationLevel::Task => {
                 if let Some(task) = self.tasks.get(self.selected_index) {
                     self.navigation.selected_task = Some(task.clone());
-                    self.loading = true;
+                    self.clear_filter();
                     self.status_message = format!("Loading containers for task {}...", task.task_id);
+                    let op_id = self.start_operation(format!("Loading containers for task {}", task.task_id));
 
                     let client = self.aws_client.clone();
                     let region = self.navigation.selected_region.as_ref().unwrap().name.clone();
@@ -306,10 +1959,15 @@ impl App {
                     tokio::spawn(async move {
                         match client.list_containers(&region, &cluster_arn, &task_arn).await {
                             Ok(containers) => {
-                                let _ = tx.send(AppEvent::ContainersLoaded(containers)).await;
+                                let _ = tx.send(AppEvent::ContainersLoaded { containers, op_id }).await;
                             }
                             Err(e) => {
-                                let _ = tx.send(AppEvent::Error(format!("Failed to load containers: {}", e))).await;
+                                let _ = tx
+                                    .send(AppEvent::Error {
+                                        message: format!("Failed to load containers: {}", e),
+                                        op_id: Some(op_id),
+                                    })
+                                    .await;
                             }
                         }
                     });
@@ -321,6 +1979,36 @@ impl App {
             NavigationLevel::Ec2Instance => {
                 // Already at deepest level for EC2
             }
+            NavigationLevel::RdsCluster => {
+                if let Some(cluster) = self.rds_clusters.get(self.selected_index) {
+                    self.navigation.selected_rds_cluster = Some(cluster.clone());
+                    self.clear_filter();
+                    self.status_message = format!("Loading instances in {}...", cluster.identifier);
+                    let op_id = self.start_operation(format!("Loading instances in {}", cluster.identifier));
+
+                    let client = self.aws_client.clone();
+                    let region = self.navigation.selected_region.as_ref().unwrap().name.clone();
+                    let cluster_identifier = cluster.identifier.clone();
+                    tokio::spawn(async move {
+                        match client.list_rds_instances_for_cluster(&region, &cluster_identifier).await {
+                            Ok(instances) => {
+                                let _ = tx.send(AppEvent::RdsInstancesLoaded { instances, op_id }).await;
+                            }
+                            Err(e) => {
+                                let _ = tx
+                                    .send(AppEvent::Error {
+                                        message: format!("Failed to load RDS instances: {}", e),
+                                        op_id: Some(op_id),
+                                    })
+                                    .await;
+                            }
+                        }
+                    });
+                }
+            }
+            NavigationLevel::RdsInstance => {
+                // Already at deepest level for RDS
+            }
         }
         Ok(())
     }
@@ -328,6 +2016,7 @@ impl App {
     pub fn go_back(&mut self) {
         self.selected_index = 0;
         self.error_message = None;
+        self.clear_filter();
 
         match self.navigation.level {
             NavigationLevel::Region => {
@@ -336,6 +2025,7 @@ impl App {
             NavigationLevel::ServiceType => {
                 self.navigation.level = NavigationLevel::Region;
                 self.navigation.service_type = None;
+                self.navigation.aggregate_regions = false;
                 self.status_message = "Select a region".to_string();
             }
             NavigationLevel::Cluster => {
@@ -347,6 +2037,7 @@ impl App {
             NavigationLevel::Service => {
                 self.navigation.level = NavigationLevel::Cluster;
                 self.navigation.selected_service = None;
+                self.navigation.active_rollout = None;
                 self.services.clear();
                 self.status_message = "Select a cluster".to_string();
             }
@@ -363,33 +2054,51 @@ impl App {
                 self.status_message = "Select a task".to_string();
             }
             NavigationLevel::Ec2Instance => {
+                self.cancel_lifecycle_poll();
                 self.navigation.level = NavigationLevel::ServiceType;
                 self.navigation.selected_ec2_instance = None;
                 self.ec2_instances.clear();
                 self.status_message = "Select a service type".to_string();
             }
+            NavigationLevel::RdsCluster => {
+                self.navigation.level = NavigationLevel::ServiceType;
+                self.navigation.selected_rds_cluster = None;
+                self.rds_clusters.clear();
+                self.status_message = "Select a service type".to_string();
+            }
+            NavigationLevel::RdsInstance => {
+                self.cancel_lifecycle_poll();
+                self.navigation.level = NavigationLevel::RdsCluster;
+                self.navigation.selected_rds_instance = None;
+                self.rds_instances.clear();
+                self.status_message = "Select a cluster".to_string();
+            }
         }
     }
 
     pub async fn refresh(&mut self, tx: mpsc::Sender<AppEvent>) -> Result<()> {
-        self.selected_index = 0;
-
         match self.navigation.level {
             NavigationLevel::Region | NavigationLevel::ServiceType => {
                 // Nothing to refresh at region or service type level
             }
             NavigationLevel::Cluster => {
                 if let Some(region) = &self.navigation.selected_region {
-                    self.loading = true;
+                    let op_id = self.start_operation("Refreshing clusters");
                     let client = self.aws_client.clone();
                     let region_name = region.name.clone();
+                    let _ = client.invalidate_cache(&region_name, "clusters");
                     tokio::spawn(async move {
                         match client.list_clusters(&region_name).await {
                             Ok(clusters) => {
-                                let _ = tx.send(AppEvent::ClustersLoaded(clusters)).await;
+                                let _ = tx.send(AppEvent::ClustersLoaded { clusters, op_id }).await;
                             }
                             Err(e) => {
-                                let _ = tx.send(AppEvent::Error(format!("Failed to refresh: {}", e))).await;
+                                let _ = tx
+                                    .send(AppEvent::Error {
+                                        message: format!("Failed to refresh: {}", e),
+                                        op_id: Some(op_id),
+                                    })
+                                    .await;
                             }
                         }
                     });
@@ -398,100 +2107,565 @@ impl App {
             NavigationLevel::Service => {
                 if let (Some(region), Some(cluster)) =
                     (&self.navigation.selected_region, &self.navigation.selected_cluster) {
-                    self.loading = true;
+                    let op_id = self.start_operation("Refreshing services");
                     let client = self.aws_client.clone();
                     let region_name = region.name.clone();
                     let cluster_arn = cluster.arn.clone();
+                    let _ = client.invalidate_cache(&region_name, "services");
                     tokio::spawn(async move {
                         match client.list_services(&region_name, &cluster_arn).await {
                             Ok(services) => {
-                                let _ = tx.send(AppEvent::ServicesLoaded(services)).await;
+                                let _ = tx.send(AppEvent::ServicesLoaded { services, op_id }).await;
+                            }
+                            Err(e) => {
+                                let _ = tx
+                                    .send(AppEvent::Error {
+                                        message: format!("Failed to refresh: {}", e),
+                                        op_id: Some(op_id),
+                                    })
+                                    .await;
+                            }
+                        }
+                    });
+                }
+            }
+            NavigationLevel::Task => {
+                if let (Some(region), Some(cluster), Some(service)) = (
+                    &self.navigation.selected_region,
+                    &self.navigation.selected_cluster,
+                    &self.navigation.selected_service,
+                ) {
+                    let op_id = self.start_operation("Refreshing tasks");
+                    let client = self.aws_client.clone();
+                    let region_name = region.name.clone();
+                    let cluster_arn = cluster.arn.clone();
+                    let service_name = service.name.clone();
+                    let _ = client.invalidate_cache(&region_name, "tasks");
+                    tokio::spawn(async move {
+                        match client.list_tasks(&region_name, &cluster_arn, &service_name).await {
+                            Ok(tasks) => {
+                                let _ = tx.send(AppEvent::TasksLoaded { tasks, op_id }).await;
+                            }
+                            Err(e) => {
+                                let _ = tx
+                                    .send(AppEvent::Error {
+                                        message: format!("Failed to refresh: {}", e),
+                                        op_id: Some(op_id),
+                                    })
+                                    .await;
+                            }
+                        }
+                    });
+                }
+            }
+            NavigationLevel::Container => {
+                if let (Some(region), Some(cluster), Some(task)) = (
+                    &self.navigation.selected_region,
+                    &self.navigation.selected_cluster,
+                    &self.navigation.selected_task,
+                ) {
+                    let op_id = self.start_operation("Refreshing containers");
+                    let client = self.aws_client.clone();
+                    let region_name = region.name.clone();
+                    let cluster_arn = cluster.arn.clone();
+                    let task_arn = task.arn.clone();
+                    tokio::spawn(async move {
+                        match client.list_containers(&region_name, &cluster_arn, &task_arn).await {
+                            Ok(containers) => {
+                                let _ = tx.send(AppEvent::ContainersLoaded { containers, op_id }).await;
+                            }
+                            Err(e) => {
+                                let _ = tx
+                                    .send(AppEvent::Error {
+                                        message: format!("Failed to refresh: {}", e),
+                                        op_id: Some(op_id),
+                                    })
+                                    .await;
+                            }
+                        }
+                    });
+                }
+            }
+            NavigationLevel::Ec2Instance => {
+                if let Some(region) = &self.navigation.selected_region {
+                    let op_id = self.start_operation("Refreshing EC2 instances");
+                    let client = self.aws_client.clone();
+                    let region_name = region.name.clone();
+                    let filters = self
+                        .current_environment_config()
+                        .map(|env| env.filters.clone())
+                        .unwrap_or_default();
+                    let _ = client.invalidate_cache(&region_name, "ec2_instances");
+                    tokio::spawn(async move {
+                        match client.list_ec2_instances_filtered(&region_name, &filters).await {
+                            Ok(instances) => {
+                                let _ = tx.send(AppEvent::Ec2InstancesLoaded { instances, op_id }).await;
                             }
                             Err(e) => {
-                                let _ = tx.send(AppEvent::Error(format!("Failed to refresh: {}", e))).await;
+                                let _ = tx
+                                    .send(AppEvent::Error {
+                                        message: format!("Failed to refresh: {}", e),
+                                        op_id: Some(op_id),
+                                    })
+                                    .await;
                             }
                         }
                     });
                 }
             }
-            NavigationLevel::Task | NavigationLevel::Container | NavigationLevel::Ec2Instance => {
-                // Similar refresh logic for tasks, containers, and EC2 instances
+            NavigationLevel::RdsCluster => {
+                if let Some(region) = &self.navigation.selected_region {
+                    let op_id = self.start_operation("Refreshing RDS clusters");
+                    let client = self.aws_client.clone();
+                    let region_name = region.name.clone();
+                    let _ = client.invalidate_cache(&region_name, "rds_clusters");
+                    tokio::spawn(async move {
+                        match client.list_rds_clusters(&region_name).await {
+                            Ok(clusters) => {
+                                let _ = tx.send(AppEvent::RdsClustersLoaded { clusters, op_id }).await;
+                            }
+                            Err(e) => {
+                                let _ = tx
+                                    .send(AppEvent::Error {
+                                        message: format!("Failed to refresh: {}", e),
+                                        op_id: Some(op_id),
+                                    })
+                                    .await;
+                            }
+                        }
+                    });
+                }
+            }
+            NavigationLevel::RdsInstance => {
+                if let (Some(region), Some(cluster)) = (
+                    &self.navigation.selected_region,
+                    &self.navigation.selected_rds_cluster,
+                ) {
+                    let op_id = self.start_operation("Refreshing RDS instances");
+                    let client = self.aws_client.clone();
+                    let region_name = region.name.clone();
+                    let cluster_identifier = cluster.identifier.clone();
+                    let _ = client.invalidate_cache(&region_name, "rds_instances");
+                    tokio::spawn(async move {
+                        match client
+                            .list_rds_instances_for_cluster(&region_name, &cluster_identifier)
+                            .await
+                        {
+                            Ok(instances) => {
+                                let _ = tx.send(AppEvent::RdsInstancesLoaded { instances, op_id }).await;
+                            }
+                            Err(e) => {
+                                let _ = tx
+                                    .send(AppEvent::Error {
+                                        message: format!("Failed to refresh: {}", e),
+                                        op_id: Some(op_id),
+                                    })
+                                    .await;
+                            }
+                        }
+                    });
+                }
             }
         }
         Ok(())
     }
 
-    pub async fn handle_event(&mut self, event: AppEvent) -> Result<()> {
-        self.loading = false;
-
+    pub async fn handle_event(&mut self, event: AppEvent, tx: mpsc::Sender<AppEvent>) -> Result<()> {
         match event {
-            AppEvent::ClustersLoaded(clusters) => {
+            AppEvent::RegionsLoaded { regions, op_id } => {
+                self.finish_operation(op_id);
+                self.status_message = format!("Found {} enabled regions", regions.len());
+                // Re-point the region cursor at the current environment's
+                // configured region, same as the initial selection in `new`.
+                if self.navigation.level == NavigationLevel::Region {
+                    self.selected_index = self
+                        .current_environment
+                        .as_ref()
+                        .and_then(|name| self.config.environments.get(name))
+                        .and_then(|env| regions.iter().position(|r| r.name == env.aws_region))
+                        .unwrap_or(0);
+                }
+                self.regions = regions;
+            }
+            AppEvent::ClustersLoaded { clusters, op_id } => {
+                self.finish_operation(op_id);
+                let was_empty = self.clusters.is_empty();
+                let selected_key = self.clusters.get(self.selected_index).map(|c| c.arn.clone());
+                let (selected_index, summary) =
+                    diff_by_key(&self.clusters, &clusters, |c| c.arn.clone(), selected_key.as_ref());
                 self.clusters = clusters;
                 self.navigation.level = NavigationLevel::Cluster;
-                self.selected_index = 0;
-                self.status_message = format!("Found {} clusters", self.clusters.len());
+                self.selected_index = selected_index;
+                self.status_message = if was_empty {
+                    format!("Found {} clusters", self.clusters.len())
+                } else {
+                    format!("Clusters: {}", summary)
+                };
             }
-            AppEvent::ServicesLoaded(services) => {
+            AppEvent::ServicesLoaded { services, op_id } => {
+                self.finish_operation(op_id);
+                let was_empty = self.services.is_empty();
+                let selected_key = self.services.get(self.selected_index).map(|s| s.name.clone());
+                let (selected_index, summary) =
+                    diff_by_key(&self.services, &services, |s| s.name.clone(), selected_key.as_ref());
                 self.services = services;
                 self.navigation.level = NavigationLevel::Service;
-                self.selected_index = 0;
-                self.status_message = format!("Found {} services", self.services.len());
+                self.selected_index = selected_index;
+                self.status_message = if was_empty {
+                    format!("Found {} services", self.services.len())
+                } else {
+                    format!("Services: {}", summary)
+                };
             }
-            AppEvent::TasksLoaded(tasks) => {
+            AppEvent::TasksLoaded { tasks, op_id } => {
+                self.finish_operation(op_id);
+                let was_empty = self.tasks.is_empty();
+                let selected_key = self.tasks.get(self.selected_index).map(|t| t.arn.clone());
+                let (selected_index, summary) =
+                    diff_by_key(&self.tasks, &tasks, |t| t.arn.clone(), selected_key.as_ref());
                 self.tasks = tasks;
                 self.navigation.level = NavigationLevel::Task;
-                self.selected_index = 0;
-                self.status_message = format!("Found {} tasks", self.tasks.len());
+                self.selected_index = selected_index;
+                self.status_message = if was_empty {
+                    format!("Found {} tasks", self.tasks.len())
+                } else {
+                    format!("Tasks: {}", summary)
+                };
             }
-            AppEvent::ContainersLoaded(containers) => {
+            AppEvent::ContainersLoaded { containers, op_id } => {
+                self.finish_operation(op_id);
+                let was_empty = self.containers.is_empty();
+                let selected_key = self.containers.get(self.selected_index).map(|c| c.name.clone());
+                let (selected_index, summary) = diff_by_key(
+                    &self.containers,
+                    &containers,
+                    |c| c.name.clone(),
+                    selected_key.as_ref(),
+                );
                 self.containers = containers;
                 self.navigation.level = NavigationLevel::Container;
-                self.selected_index = 0;
-                self.status_message = format!("Found {} containers", self.containers.len());
+                self.selected_index = selected_index;
+                self.status_message = if was_empty {
+                    format!("Found {} containers", self.containers.len())
+                } else {
+                    format!("Containers: {}", summary)
+                };
             }
-            AppEvent::Ec2InstancesLoaded(instances) => {
+            AppEvent::Ec2InstancesLoaded { instances, op_id } => {
+                self.finish_operation(op_id);
+                let was_empty = self.ec2_instances.is_empty();
+                let selected_key = self
+                    .ec2_instances
+                    .get(self.selected_index)
+                    .map(|i| i.instance_id.clone());
+                let (selected_index, summary) = diff_by_key(
+                    &self.ec2_instances,
+                    &instances,
+                    |i| i.instance_id.clone(),
+                    selected_key.as_ref(),
+                );
                 self.ec2_instances = instances;
                 self.navigation.level = NavigationLevel::Ec2Instance;
-                self.selected_index = 0;
-                self.status_message = format!("Found {} EC2 instances", self.ec2_instances.len());
+                self.selected_index = selected_index;
+                self.status_message = if was_empty {
+                    format!("Found {} EC2 instances", self.ec2_instances.len())
+                } else {
+                    format!("EC2 instances: {}", summary)
+                };
             }
-            AppEvent::DeploymentTriggered(service_name) => {
-                self.status_message = format!("Deployment triggered for {}", service_name);
+            AppEvent::RdsClustersLoaded { clusters, op_id } => {
+                self.finish_operation(op_id);
+                let was_empty = self.rds_clusters.is_empty();
+                let selected_key = self
+                    .rds_clusters
+                    .get(self.selected_index)
+                    .map(|c| c.identifier.clone());
+                let (selected_index, summary) = diff_by_key(
+                    &self.rds_clusters,
+                    &clusters,
+                    |c| c.identifier.clone(),
+                    selected_key.as_ref(),
+                );
+                self.rds_clusters = clusters;
+                self.navigation.level = NavigationLevel::RdsCluster;
+                self.selected_index = selected_index;
+                self.status_message = if was_empty {
+                    format!("Found {} RDS clusters", self.rds_clusters.len())
+                } else {
+                    format!("RDS clusters: {}", summary)
+                };
             }
-            AppEvent::Error(msg) => {
-                self.error_message = Some(msg);
+            AppEvent::RdsInstancesLoaded { instances, op_id } => {
+                self.finish_operation(op_id);
+                let was_empty = self.rds_instances.is_empty();
+                let selected_key = self
+                    .rds_instances
+                    .get(self.selected_index)
+                    .map(|i| i.identifier.clone());
+                let (selected_index, summary) = diff_by_key(
+                    &self.rds_instances,
+                    &instances,
+                    |i| i.identifier.clone(),
+                    selected_key.as_ref(),
+                );
+                self.rds_instances = instances;
+                self.navigation.level = NavigationLevel::RdsInstance;
+                self.selected_index = selected_index;
+                self.status_message = if was_empty {
+                    format!("Found {} RDS instances", self.rds_instances.len())
+                } else {
+                    format!("RDS instances: {}", summary)
+                };
+            }
+            AppEvent::DeploymentTriggered { service, op_id } => {
+                self.status_message = format!("Deployment triggered for {}", service);
+                if let Some(op) = self.operations.iter_mut().find(|op| op.id == op_id) {
+                    op.name = format!("Deploying {}", service);
+                }
+            }
+            AppEvent::DeploymentProgress {
+                service,
+                rollout_state,
+                running,
+                desired,
+                pending,
+                op_id,
+            } => {
+                self.status_message = format!(
+                    "{} rollout {:?}: {}/{} running, {} pending",
+                    service, rollout_state, running, desired, pending
+                );
+                if !matches!(rollout_state, RolloutState::InProgress) {
+                    self.finish_operation(op_id);
+                }
+                self.navigation.active_rollout = Some(ActiveRollout {
+                    service_name: service,
+                    status: DeploymentStatus { rollout_state, running, desired, pending },
+                });
+            }
+            AppEvent::LifecycleActionTriggered { resource_name, op_id } => {
+                self.status_message = format!("Action triggered for {}, waiting for it to take effect...", resource_name);
+                if let Some(op) = self.operations.iter_mut().find(|op| op.id == op_id) {
+                    op.name = format!("Waiting on {}", resource_name);
+                }
+            }
+            AppEvent::LifecycleStateChanged { resource_name, state, terminal, op_id } => {
+                self.status_message = format!("{} is now '{}'", resource_name, state);
+                if terminal {
+                    self.finish_operation(op_id);
+                    self.lifecycle_task_handle = None;
+                }
+            }
+            AppEvent::Error { message, op_id } => {
+                if let Some(op_id) = op_id {
+                    self.fail_operation(op_id, message.clone());
+                }
+                self.error_message = Some(message);
                 self.status_message = "Error occurred".to_string();
             }
+            AppEvent::LogLine(line) => {
+                self.log_lines.push(line);
+                if self.log_follow {
+                    self.log_scroll_offset = self.log_lines.len().saturating_sub(1);
+                }
+            }
+            AppEvent::LogStreamEnded => {
+                self.log_lines.push("-- log stream ended --".to_string());
+            }
+            AppEvent::RefreshTick => {
+                if self.auto_refresh_enabled {
+                    self.refresh(tx).await?;
+                }
+            }
         }
 
         Ok(())
     }
 
+    /// Open an interactive shell (ECS Exec or SSH) as an embedded PTY pane
+    /// instead of handing the terminal to a child process. While
+    /// `active_shell` is `Some`, the event loop forwards keystrokes straight
+    /// into the PTY rather than treating them as navigation commands.
     pub async fn execute_command(&mut self) -> Result<()> {
         match self.navigation.level {
             NavigationLevel::Container => {
-                if let Some(container) = self.containers.get(self.selected_index) {
+                let target = if let Some(container) = self.containers.get(self.selected_index) {
                     if let (Some(region), Some(cluster), Some(task)) = (
                         &self.navigation.selected_region,
                         &self.navigation.selected_cluster,
                         &self.navigation.selected_task,
                     ) {
-                        self.status_message = format!("Starting ECS Exec session for {}...", container.name);
-
-                        crate::terminal::start_ecs_exec(
-                            &region.name,
-                            &cluster.arn,
-                            &task.arn,
-                            &container.name,
-                        ).await?;
+                        Some((
+                            region.name.clone(),
+                            cluster.arn.clone(),
+                            task.arn.clone(),
+                            container.name.clone(),
+                            container.runtime_id.clone(),
+                        ))
+                    } else {
+                        None
+                    }
+                } else {
+                    None
+                };
+
+                if let Some((region_name, cluster_arn, task_arn, container_name, runtime_id)) = target
+                {
+                    self.status_message =
+                        format!("Checking ECS Exec prerequisites for {}...", container_name);
+
+                    match self
+                        .aws_client
+                        .check_exec_enabled(&region_name, &cluster_arn, &task_arn, &container_name)
+                        .await
+                    {
+                        Ok(()) => {
+                            let Some(runtime_id) = runtime_id else {
+                                self.error_message = Some(
+                                    "Container has no runtime_id yet (task still starting?)"
+                                        .to_string(),
+                                );
+                                return Ok(());
+                            };
+
+                            let env = self.current_environment_config();
+                            let default_command = env
+                                .and_then(|e| e.exec_command.clone())
+                                .unwrap_or_else(|| "/bin/sh".to_string());
+                            let default_user = env.and_then(|e| e.exec_user.clone());
+
+                            let (command, user) = match crate::terminal::prompt_exec_options(
+                                &default_command,
+                                default_user.as_deref(),
+                            ) {
+                                Ok(choice) => choice,
+                                Err(e) => {
+                                    self.error_message =
+                                        Some(format!("Failed to read exec options: {}", e));
+                                    return Ok(());
+                                }
+                            };
+
+                            // `execute-command` always connects as root, so
+                            // running as another user means wrapping the
+                            // command in `su`. Both values came from a free-
+                            // form prompt, so single-quote-escape them before
+                            // interpolating into the shell string.
+                            let command = match user {
+                                Some(user) => format!(
+                                    "su - '{}' -c '{}'",
+                                    shell_single_quote(&user),
+                                    shell_single_quote(&command)
+                                ),
+                                None => command,
+                            };
+
+                            self.status_message =
+                                format!("Starting ECS Exec session for {}...", container_name);
+
+                            match self
+                                .aws_client
+                                .start_container_session(
+                                    &region_name,
+                                    &cluster_arn,
+                                    &task_arn,
+                                    &container_name,
+                                    &runtime_id,
+                                    &command,
+                                )
+                                .await
+                            {
+                                Ok(descriptor) => {
+                                    let (program, args) = self.wrap_with_profile(
+                                        "session-manager-plugin".to_string(),
+                                        descriptor.plugin_args(),
+                                    );
+                                    self.open_shell(
+                                        format!("exec: {}", container_name),
+                                        &program,
+                                        &args,
+                                    );
+                                }
+                                Err(e) => {
+                                    self.error_message =
+                                        Some(format!("Failed to start ECS Exec session: {}", e));
+                                }
+                            }
+                        }
+                        Err(e) => {
+                            self.error_message = Some(format!("Cannot start ECS Exec: {}", e));
+                        }
                     }
                 }
             }
             NavigationLevel::Ec2Instance => {
-                if let Some(instance) = self.ec2_instances.get(self.selected_index) {
-                    self.status_message = format!("Starting SSH session to {}...", instance.instance_id);
+                enum Launch {
+                    Ssm { region: String, instance_id: String },
+                    Ssh { title: String, program: String, args: Vec<String> },
+                    None,
+                    Err(String),
+                }
+
+                let launch = if let Some(instance) = self.ec2_instances.get(self.selected_index) {
+                    let env = self.current_environment_config();
+                    let prefer_ssm = env.map(|e| e.use_ssm).unwrap_or(false);
+
+                    if instance.ssm_managed && (prefer_ssm || env.is_none()) {
+                        Launch::Ssm {
+                            region: instance.region.clone(),
+                            instance_id: instance.instance_id.clone(),
+                        }
+                    } else if let Some(ip) = instance.public_ip.as_ref().or(instance.private_ip.as_ref()) {
+                        let username = env
+                            .and_then(|e| e.ssh_user.clone())
+                            .unwrap_or_else(|| "ec2-user".to_string());
+                        let key_path = env.and_then(|e| e.ssh_key_path.clone());
+
+                        let mut args = Vec::new();
+                        if let Some(key_path) = key_path {
+                            args.push("-i".to_string());
+                            args.push(key_path);
+                        }
+                        args.push(format!("{}@{}", username, ip));
 
-                    crate::terminal::start_ssh_session(instance).await?;
+                        Launch::Ssh {
+                            title: format!("ssh: {}", instance.instance_id),
+                            program: "ssh".to_string(),
+                            args,
+                        }
+                    } else {
+                        Launch::Err("No IP address available for this instance".to_string())
+                    }
+                } else {
+                    Launch::None
+                };
+
+                match launch {
+                    Launch::Ssm { region, instance_id } => {
+                        self.status_message =
+                            format!("Starting SSM session to {}...", instance_id);
+
+                        match self.aws_client.start_instance_session(&region, &instance_id).await {
+                            Ok(descriptor) => {
+                                let (program, args) = self.wrap_with_profile(
+                                    "session-manager-plugin".to_string(),
+                                    descriptor.plugin_args(),
+                                );
+                                self.open_shell(format!("ssh: {}", instance_id), &program, &args);
+                            }
+                            Err(e) => {
+                                self.error_message =
+                                    Some(format!("Failed to start SSM session: {}", e));
+                            }
+                        }
+                    }
+                    Launch::Ssh { title, program, args } => {
+                        self.status_message = format!("Starting SSH session to {}...", title);
+                        let (program, args) = self.wrap_with_profile(program, args);
+                        self.open_shell(title, &program, &args);
+                    }
+                    Launch::None => {}
+                    Launch::Err(msg) => self.error_message = Some(msg),
                 }
             }
             _ => {}
@@ -499,6 +2673,198 @@ impl App {
         Ok(())
     }
 
+    fn open_shell(&mut self, title: String, program: &str, args: &[String]) {
+        match crate::pty::PtySession::spawn(title, program, args, 24, 80) {
+            Ok(session) => self.active_shell = Some(session),
+            Err(e) => self.error_message = Some(format!("Failed to start session: {}", e)),
+        }
+    }
+
+    pub fn send_shell_input(&mut self, bytes: &[u8]) -> Result<()> {
+        if let Some(shell) = self.active_shell.as_mut() {
+            shell.write_input(bytes)?;
+        }
+        Ok(())
+    }
+
+    /// Close the embedded shell pane, killing the underlying process.
+    pub fn close_shell(&mut self) {
+        if let Some(mut shell) = self.active_shell.take() {
+            shell.kill();
+        }
+    }
+
+    /// Drop the shell pane once its process has exited on its own, so the
+    /// console is shown again without the user needing to notice and close it.
+    pub fn reap_shell_if_exited(&mut self) {
+        if let Some(shell) = self.active_shell.as_mut() {
+            if !shell.is_alive() {
+                self.active_shell = None;
+            }
+        }
+    }
+
+    /// Open the log-viewer popup for the selected container, resolving its
+    /// `awslogs` group/stream from the task definition and spawning
+    /// `tail_log_stream` to feed it. Toggles closed if the popup is already
+    /// open, same as `toggle_port_forward_panel`.
+    pub async fn open_log_viewer(&mut self, tx: mpsc::Sender<AppEvent>) -> Result<()> {
+        if self.show_log_popup {
+            self.close_log_popup();
+            return Ok(());
+        }
+
+        if self.navigation.level != NavigationLevel::Container {
+            return Ok(());
+        }
+
+        let target = if let Some(container) = self.containers.get(self.selected_index) {
+            if let (Some(region), Some(cluster), Some(task)) = (
+                &self.navigation.selected_region,
+                &self.navigation.selected_cluster,
+                &self.navigation.selected_task,
+            ) {
+                Some((
+                    region.name.clone(),
+                    cluster.arn.clone(),
+                    task.arn.clone(),
+                    container.name.clone(),
+                ))
+            } else {
+                None
+            }
+        } else {
+            None
+        };
+
+        let Some((region_name, cluster_arn, task_arn, container_name)) = target else {
+            return Ok(());
+        };
+
+        self.status_message = format!("Resolving log configuration for {}...", container_name);
+
+        match self
+            .aws_client
+            .resolve_container_log_config(&region_name, &cluster_arn, &task_arn, &container_name)
+            .await
+        {
+            Ok((log_group, log_stream)) => {
+                self.log_lines.clear();
+                self.log_scroll_offset = 0;
+                self.log_follow = true;
+                self.show_log_popup = true;
+                self.status_message = format!("Tailing logs for {}...", container_name);
+
+                let client = self.aws_client.clone();
+                let handle = tokio::spawn(tail_log_stream(
+                    client, tx, region_name, log_group, log_stream,
+                ));
+                self.log_stream_handle = Some(handle);
+            }
+            Err(e) => {
+                self.error_message = Some(format!("Failed to resolve log configuration: {}", e));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Close the log popup and cancel its tailing task.
+    pub fn close_log_popup(&mut self) {
+        self.show_log_popup = false;
+        self.log_lines.clear();
+        self.log_scroll_offset = 0;
+        if let Some(handle) = self.log_stream_handle.take() {
+            handle.abort();
+        }
+    }
+
+    /// Abort a lifecycle action's reconcile-wait loop, if one is running,
+    /// without undoing the start/stop/reboot/terminate call already sent -
+    /// just stop waiting around for it to finish.
+    pub fn cancel_lifecycle_poll(&mut self) {
+        if let Some(handle) = self.lifecycle_task_handle.take() {
+            handle.abort();
+            self.status_message = "Stopped waiting for instance state change".to_string();
+        }
+    }
+
+    /// Scroll up one line and stop following new lines, so the user can read
+    /// back through history without it jumping away under them.
+    pub fn log_scroll_up(&mut self) {
+        self.log_follow = false;
+        self.log_scroll_offset = self.log_scroll_offset.saturating_sub(1);
+    }
+
+    pub fn log_scroll_down(&mut self) {
+        let max_offset = self.log_lines.len().saturating_sub(1);
+        self.log_scroll_offset = (self.log_scroll_offset + 1).min(max_offset);
+        if self.log_scroll_offset == max_offset {
+            self.log_follow = true;
+        }
+    }
+
+    pub fn toggle_log_follow(&mut self) {
+        self.log_follow = !self.log_follow;
+        if self.log_follow {
+            self.log_scroll_offset = self.log_lines.len().saturating_sub(1);
+        }
+    }
+
+    pub fn toggle_port_forward_panel(&mut self) {
+        self.show_port_forward_panel = !self.show_port_forward_panel;
+    }
+
+    /// Prompt for remote/local ports and open an SSM port-forwarding tunnel
+    /// to the currently selected EC2 instance, tracking it so it can be
+    /// cleaned up on quit or cancellation.
+    pub async fn start_port_forward(&mut self) -> Result<()> {
+        if self.navigation.level != NavigationLevel::Ec2Instance {
+            return Ok(());
+        }
+
+        if let Some(instance) = self.ec2_instances.get(self.selected_index) {
+            let region = instance.region.clone();
+            let instance_id = instance.instance_id.clone();
+
+            match crate::terminal::start_port_forward_session(&region, &instance_id).await {
+                Ok(session) => {
+                    self.status_message = format!(
+                        "Tunnel localhost:{} -> {}:{} is up",
+                        session.local_port, instance_id, session.remote_port
+                    );
+                    self.port_forward_sessions.push(session);
+                }
+                Err(e) => {
+                    self.error_message = Some(format!("Failed to start port forward: {}", e));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Kill and forget a single tunnel by its index in `port_forward_sessions`.
+    pub fn stop_port_forward(&mut self, index: usize) {
+        if index < self.port_forward_sessions.len() {
+            let mut session = self.port_forward_sessions.remove(index);
+            let _ = session.child.kill();
+            self.status_message = format!(
+                "Closed tunnel to {} (port {})",
+                session.instance_id, session.remote_port
+            );
+        }
+    }
+
+    /// Kill every active tunnel. Must be called during teardown so we never
+    /// leave orphaned `aws ssm start-session` processes behind when the app
+    /// exits.
+    pub fn stop_all_port_forward_sessions(&mut self) {
+        for mut session in self.port_forward_sessions.drain(..) {
+            let _ = session.child.kill();
+        }
+    }
+
     pub async fn force_deployment(&mut self, tx: mpsc::Sender<AppEvent>) -> Result<()> {
         if self.navigation.level != NavigationLevel::Service {
             return Ok(());
@@ -509,8 +2875,8 @@ impl App {
                 &self.navigation.selected_region,
                 &self.navigation.selected_cluster,
             ) {
-                self.loading = true;
                 self.status_message = format!("Triggering deployment for {}...", service.name);
+                let op_id = self.start_operation(format!("Deploying {}", service.name));
 
                 let client = self.aws_client.clone();
                 let region_name = region.name.clone();
@@ -522,21 +2888,355 @@ impl App {
                         .force_new_deployment(&region_name, &cluster_arn, &service_name)
                         .await
                     {
+                        Ok(deployment_id) => {
+                            let _ = tx
+                                .send(AppEvent::DeploymentTriggered { service: service_name.clone(), op_id })
+                                .await;
+                            poll_rollout(client, tx, region_name, cluster_arn, service_name, deployment_id, op_id)
+                                .await;
+                        }
+                        Err(e) => {
+                            let _ = tx
+                                .send(AppEvent::Error {
+                                    message: format!("Failed to trigger deployment: {}", e),
+                                    op_id: Some(op_id),
+                                })
+                                .await;
+                        }
+                    }
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Dispatch a start/stop/reboot/terminate action for the selected
+    /// EC2/RDS instance, then spawn a reconcile loop that polls until it
+    /// reaches the requested terminal state - cancellable with Esc via
+    /// `cancel_lifecycle_poll`.
+    pub async fn invoke_lifecycle_action(
+        &mut self,
+        action: ContextAction,
+        tx: mpsc::Sender<AppEvent>,
+    ) -> Result<()> {
+        match action {
+            ContextAction::StartInstance
+            | ContextAction::StopInstance
+            | ContextAction::RebootInstance
+            | ContextAction::TerminateInstance => {
+                if self.navigation.level != NavigationLevel::Ec2Instance {
+                    return Ok(());
+                }
+                let Some(instance) = self.ec2_instances.get(self.selected_index).cloned() else {
+                    return Ok(());
+                };
+
+                let (verb, target_state): (&str, &'static str) = match action {
+                    ContextAction::StartInstance => ("Starting", "running"),
+                    ContextAction::StopInstance => ("Stopping", "stopped"),
+                    ContextAction::RebootInstance => ("Rebooting", "running"),
+                    ContextAction::TerminateInstance => ("Terminating", "terminated"),
+                    _ => unreachable!(),
+                };
+
+                self.status_message = format!("{} {}...", verb, instance.name);
+                let op_id = self.start_operation(format!("{} {}", verb, instance.name));
+
+                let client = self.aws_client.clone();
+                let region = instance.region.clone();
+                let instance_id = instance.instance_id.clone();
+                let resource_name = instance.name.clone();
+                let handle = tokio::spawn(async move {
+                    let result = match action {
+                        ContextAction::StartInstance => client.start_ec2_instance(&region, &instance_id).await,
+                        ContextAction::StopInstance => client.stop_ec2_instance(&region, &instance_id).await,
+                        ContextAction::RebootInstance => client.reboot_ec2_instance(&region, &instance_id).await,
+                        ContextAction::TerminateInstance => {
+                            client.terminate_ec2_instance(&region, &instance_id).await
+                        }
+                        _ => unreachable!(),
+                    };
+                    match result {
+                        Ok(()) => {
+                            let _ = tx
+                                .send(AppEvent::LifecycleActionTriggered {
+                                    resource_name: resource_name.clone(),
+                                    op_id,
+                                })
+                                .await;
+                            poll_ec2_lifecycle(client, tx, region, instance_id, resource_name, target_state, op_id)
+                                .await;
+                        }
+                        Err(e) => {
+                            let _ = tx
+                                .send(AppEvent::Error {
+                                    message: format!("Failed to {} {}: {}", verb.to_lowercase(), resource_name, e),
+                                    op_id: Some(op_id),
+                                })
+                                .await;
+                        }
+                    }
+                });
+                self.lifecycle_task_handle = Some(handle);
+            }
+            ContextAction::StartRdsInstance
+            | ContextAction::StopRdsInstance
+            | ContextAction::RebootRdsInstance => {
+                if self.navigation.level != NavigationLevel::RdsInstance {
+                    return Ok(());
+                }
+                let Some(instance) = self.rds_instances.get(self.selected_index).cloned() else {
+                    return Ok(());
+                };
+                let Some(region) = self.navigation.selected_region.as_ref().map(|r| r.name.clone()) else {
+                    return Ok(());
+                };
+
+                let (verb, target_status): (&str, &'static str) = match action {
+                    ContextAction::StartRdsInstance => ("Starting", "available"),
+                    ContextAction::StopRdsInstance => ("Stopping", "stopped"),
+                    ContextAction::RebootRdsInstance => ("Rebooting", "available"),
+                    _ => unreachable!(),
+                };
+
+                self.status_message = format!("{} {}...", verb, instance.identifier);
+                let op_id = self.start_operation(format!("{} {}", verb, instance.identifier));
+
+                let client = self.aws_client.clone();
+                let identifier = instance.identifier.clone();
+                let resource_name = instance.identifier.clone();
+                let handle = tokio::spawn(async move {
+                    let result = match action {
+                        ContextAction::StartRdsInstance => client.start_rds_instance(&region, &identifier).await,
+                        ContextAction::StopRdsInstance => client.stop_rds_instance(&region, &identifier).await,
+                        ContextAction::RebootRdsInstance => client.reboot_rds_instance(&region, &identifier).await,
+                        _ => unreachable!(),
+                    };
+                    match result {
                         Ok(()) => {
-                            let _ = tx.send(AppEvent::DeploymentTriggered(service_name)).await;
+                            let _ = tx
+                                .send(AppEvent::LifecycleActionTriggered {
+                                    resource_name: resource_name.clone(),
+                                    op_id,
+                                })
+                                .await;
+                            poll_rds_lifecycle(client, tx, region, identifier, resource_name, target_status, op_id)
+                                .await;
                         }
                         Err(e) => {
                             let _ = tx
-                                .send(AppEvent::Error(format!("Failed to trigger deployment: {}", e)))
+                                .send(AppEvent::Error {
+                                    message: format!("Failed to {} {}: {}", verb.to_lowercase(), resource_name, e),
+                                    op_id: Some(op_id),
+                                })
                                 .await;
                         }
                     }
                 });
+                self.lifecycle_task_handle = Some(handle);
             }
+            _ => {}
         }
         Ok(())
     }
 
+    /// Actions valid for the currently selected resource, in menu order.
+    /// Empty where there's nothing to do (e.g. browsing regions/clusters).
+    fn context_actions_for_current_level(&self) -> Vec<ContextAction> {
+        match self.navigation.level {
+            NavigationLevel::Container => vec![ContextAction::Exec, ContextAction::ViewLogs],
+            NavigationLevel::Ec2Instance => vec![
+                ContextAction::Ssh,
+                ContextAction::StartInstance,
+                ContextAction::StopInstance,
+                ContextAction::RebootInstance,
+                ContextAction::TerminateInstance,
+            ],
+            NavigationLevel::RdsInstance => vec![
+                ContextAction::StartRdsInstance,
+                ContextAction::StopRdsInstance,
+                ContextAction::RebootRdsInstance,
+            ],
+            NavigationLevel::Service => vec![ContextAction::ForceDeploy, ContextAction::Scale],
+            _ => Vec::new(),
+        }
+    }
+
+    /// Open the context menu for the selected item, or close it if it's
+    /// already open. A no-op when the current item has no actions.
+    pub fn toggle_context_menu(&mut self) {
+        if self.show_context_menu {
+            self.close_context_menu();
+            return;
+        }
+
+        let actions = self.context_actions_for_current_level();
+        if actions.is_empty() {
+            return;
+        }
+
+        self.context_menu_actions = actions;
+        self.context_menu_index = 0;
+        self.show_context_menu = true;
+    }
+
+    pub fn close_context_menu(&mut self) {
+        self.show_context_menu = false;
+        self.context_menu_actions.clear();
+        self.context_menu_index = 0;
+    }
+
+    pub fn context_menu_next(&mut self) {
+        if !self.context_menu_actions.is_empty() {
+            self.context_menu_index =
+                (self.context_menu_index + 1) % self.context_menu_actions.len();
+        }
+    }
+
+    pub fn context_menu_previous(&mut self) {
+        if !self.context_menu_actions.is_empty() {
+            self.context_menu_index = if self.context_menu_index == 0 {
+                self.context_menu_actions.len() - 1
+            } else {
+                self.context_menu_index - 1
+            };
+        }
+    }
+
+    /// Invoke the highlighted context menu entry, closing the menu first so
+    /// the action's own status/error messages aren't immediately clobbered
+    /// by the menu still being drawn.
+    pub async fn invoke_context_action(&mut self, tx: mpsc::Sender<AppEvent>) -> Result<()> {
+        let Some(action) = self.context_menu_actions.get(self.context_menu_index).copied() else {
+            self.close_context_menu();
+            return Ok(());
+        };
+        self.close_context_menu();
+
+        match action {
+            ContextAction::Exec | ContextAction::Ssh => self.execute_command().await,
+            ContextAction::ViewLogs => self.open_log_viewer(tx).await,
+            ContextAction::ForceDeploy => self.force_deployment(tx).await,
+            ContextAction::StartInstance
+            | ContextAction::StopInstance
+            | ContextAction::RebootInstance
+            | ContextAction::TerminateInstance
+            | ContextAction::StartRdsInstance
+            | ContextAction::StopRdsInstance
+            | ContextAction::RebootRdsInstance => self.invoke_lifecycle_action(action, tx).await,
+            ContextAction::Scale => {
+                self.status_message = format!("{} isn't implemented yet", action.label());
+                Ok(())
+            }
+        }
+    }
+
+    /// Identity of the resource currently selected, used both as the
+    /// preview cache key and to detect a selection change. `None` at
+    /// navigation levels with nothing to preview (regions, clusters list,
+    /// ...).
+    fn selected_resource_key(&self) -> Option<String> {
+        match self.navigation.level {
+            NavigationLevel::Cluster => self.clusters.get(self.selected_index).map(|c| c.arn.clone()),
+            NavigationLevel::Service => self.services.get(self.selected_index).map(|s| s.arn.clone()),
+            NavigationLevel::Task => self.tasks.get(self.selected_index).map(|t| t.arn.clone()),
+            NavigationLevel::Container => self
+                .containers
+                .get(self.selected_index)
+                .map(|c| format!("{}/{}", c.name, c.runtime_id.as_deref().unwrap_or(""))),
+            NavigationLevel::Ec2Instance => {
+                self.ec2_instances.get(self.selected_index).map(|i| i.instance_id.clone())
+            }
+            NavigationLevel::RdsCluster => {
+                self.rds_clusters.get(self.selected_index).map(|c| c.arn.clone())
+            }
+            NavigationLevel::RdsInstance => {
+                self.rds_instances.get(self.selected_index).map(|i| i.arn.clone())
+            }
+            _ => None,
+        }
+    }
+
+    /// Pretty-printed JSON for the currently selected resource, serialized
+    /// from the same listing already on screen rather than re-fetching it.
+    fn selected_resource_json(&self) -> Option<String> {
+        match self.navigation.level {
+            NavigationLevel::Cluster => self
+                .clusters
+                .get(self.selected_index)
+                .and_then(|c| serde_json::to_string_pretty(c).ok()),
+            NavigationLevel::Service => self
+                .services
+                .get(self.selected_index)
+                .and_then(|s| serde_json::to_string_pretty(s).ok()),
+            NavigationLevel::Task => self
+                .tasks
+                .get(self.selected_index)
+                .and_then(|t| serde_json::to_string_pretty(t).ok()),
+            NavigationLevel::Container => self
+                .containers
+                .get(self.selected_index)
+                .and_then(|c| serde_json::to_string_pretty(c).ok()),
+            NavigationLevel::Ec2Instance => self
+                .ec2_instances
+                .get(self.selected_index)
+                .and_then(|i| serde_json::to_string_pretty(i).ok()),
+            NavigationLevel::RdsCluster => self
+                .rds_clusters
+                .get(self.selected_index)
+                .and_then(|c| serde_json::to_string_pretty(c).ok()),
+            NavigationLevel::RdsInstance => self
+                .rds_instances
+                .get(self.selected_index)
+                .and_then(|i| serde_json::to_string_pretty(i).ok()),
+            _ => None,
+        }
+    }
+
+    pub fn toggle_preview_pane(&mut self) {
+        self.show_preview_pane = !self.show_preview_pane;
+    }
+
+    /// Lazily highlight and cache the selected resource's JSON detail.
+    /// Called once per event loop tick; a no-op once the current selection
+    /// is already cached. Resets the scroll offset whenever the selection
+    /// changes, since the old offset means nothing against new content.
+    pub fn ensure_preview_loaded(&mut self) {
+        let Some(key) = self.selected_resource_key() else {
+            self.last_preview_key = None;
+            return;
+        };
+
+        if self.last_preview_key.as_deref() != Some(key.as_str()) {
+            self.preview_scroll_offset = 0;
+            self.last_preview_key = Some(key.clone());
+        }
+
+        if self.preview_cache.contains_key(&key) {
+            return;
+        }
+        let Some(json) = self.selected_resource_json() else {
+            return;
+        };
+        self.preview_cache.insert(key, crate::preview::highlight_json(&json));
+    }
+
+    /// The currently selected resource's highlighted preview lines, already
+    /// populated by `ensure_preview_loaded`.
+    pub fn preview_lines(&self) -> Option<&[crate::preview::HighlightedLine]> {
+        let key = self.selected_resource_key()?;
+        self.preview_cache.get(&key).map(|lines| lines.as_slice())
+    }
+
+    pub fn preview_scroll_up(&mut self) {
+        self.preview_scroll_offset = self.preview_scroll_offset.saturating_sub(1);
+    }
+
+    pub fn preview_scroll_down(&mut self) {
+        let max_offset = self.preview_lines().map(|lines| lines.len().saturating_sub(1)).unwrap_or(0);
+        self.preview_scroll_offset = (self.preview_scroll_offset + 1).min(max_offset);
+    }
+
     pub fn can_quit(&self) -> bool {
         true
     }
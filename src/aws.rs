@@ -1,15 +1,85 @@
-use anyhow::Result;
+use anyhow::{bail, Context, Result};
 use aws_config::BehaviorVersion;
 use aws_sdk_ecs::Client as EcsClient;
 use aws_sdk_ec2::Client as Ec2Client;
 use aws_sdk_ssm::Client as SsmClient;
 use aws_sdk_rds::Client as RdsClient;
+use aws_sdk_cloudwatchlogs::Client as LogsClient;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::app::{
+    BlockDevice, Cluster, Container, DeploymentStatus, Ec2Instance, IpPermissionInfo,
+    NetworkInterfaceInfo, RdsCluster, RdsInstance, Region, RolloutState, SecurityGroupInfo, Service,
+    Task,
+};
+use crate::cache::InventoryCache;
+use crate::errors::{with_retry, AwsError};
+use futures::stream::{self, StreamExt};
+use serde_json::json;
+
+/// Total attempts (including the first) made for a single AWS API call
+/// before a retryable error is surfaced to the caller.
+const MAX_ATTEMPTS: u32 = 4;
+
+// Per-resource-type cache lifetimes, mirroring the constant-expiry pattern
+// used for short-lived credentials: RDS listings, which change least, get the
+// longest TTL, EC2 state a minute, clusters and services half that, and
+// tasks, which churn most, the shortest.
+const CLUSTER_TTL: Duration = Duration::from_secs(30);
+const SERVICE_TTL: Duration = Duration::from_secs(30);
+const TASK_TTL: Duration = Duration::from_secs(15);
+const EC2_TTL: Duration = Duration::from_secs(60);
+const RDS_TTL: Duration = Duration::from_secs(120);
+
+/// How many regions are listed concurrently in a fan-out call. Bounded so a
+/// "list every region" sweep doesn't open dozens of simultaneous connections.
+const MAX_CONCURRENT_REGIONS: usize = 5;
+
+/// A Session Manager session returned by `ssm:StartSession` or
+/// `ecs:ExecuteCommand`, ready to be handed to the `session-manager-plugin`
+/// binary to open the actual interactive stream.
+#[derive(Debug, Clone)]
+pub struct SessionDescriptor {
+    pub session_id: String,
+    pub stream_url: String,
+    pub token_value: String,
+    pub target: String,
+    pub region: String,
+}
 
-use crate::app::{Cluster, Container, Ec2Instance, RdsCluster, RdsInstance, Service, Task};
+impl SessionDescriptor {
+    /// Build the positional argv `session-manager-plugin` expects: the
+    /// session response, region, API name, an (empty) profile, the request
+    /// parameters, and the SSM endpoint. This is undocumented-but-stable
+    /// ABI the `aws` CLI itself relies on to launch the plugin.
+    pub fn plugin_args(&self) -> Vec<String> {
+        let response = json!({
+            "SessionId": self.session_id,
+            "TokenValue": self.token_value,
+            "StreamUrl": self.stream_url,
+        })
+        .to_string();
+        let parameters = json!({ "Target": self.target }).to_string();
+
+        vec![
+            response,
+            self.region.clone(),
+            "StartSession".to_string(),
+            String::new(),
+            parameters,
+            format!("https://ssm.{}.amazonaws.com", self.region),
+        ]
+    }
+}
 
 #[derive(Clone)]
 pub struct AwsClient {
     config: aws_config::SdkConfig,
+    cache: Arc<InventoryCache>,
+    // When set, listings are served purely from cache and never hit AWS -
+    // `--offline` mode.
+    offline: bool,
 }
 
 impl AwsClient {
@@ -17,8 +87,28 @@ impl AwsClient {
         let config = aws_config::defaults(BehaviorVersion::latest())
             .load()
             .await;
+        let cache = Arc::new(InventoryCache::open()?);
+
+        Ok(Self {
+            config,
+            cache,
+            offline: false,
+        })
+    }
 
-        Ok(Self { config })
+    /// Serve listings purely from the local cache, never calling AWS. Used
+    /// for `--offline` mode so the tool stays usable without network or
+    /// credentials.
+    pub fn with_offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+
+    /// Drop cached rows for a resource type/region, so the next listing call
+    /// re-fetches from AWS instead of returning a (possibly still-fresh)
+    /// cached copy. Used by the explicit 'r' refresh binding.
+    pub fn invalidate_cache(&self, region: &str, resource_type: &str) -> Result<()> {
+        self.cache.invalidate(region, resource_type)
     }
 
     fn get_ecs_client(&self, region: &str) -> EcsClient {
@@ -57,10 +147,26 @@ impl AwsClient {
         RdsClient::from_conf(rds_config)
     }
 
+    fn get_logs_client(&self, region: &str) -> LogsClient {
+        let region_provider = aws_sdk_cloudwatchlogs::config::Region::new(region.to_string());
+        let logs_config = aws_sdk_cloudwatchlogs::config::Builder::from(&self.config)
+            .region(region_provider)
+            .build();
+
+        LogsClient::from_conf(logs_config)
+    }
+
     pub async fn list_clusters(&self, region: &str) -> Result<Vec<Cluster>> {
+        if let Some(cached) = self.cache.get::<Vec<Cluster>>(region, "clusters", "-", CLUSTER_TTL) {
+            return Ok(cached);
+        }
+        if self.offline {
+            bail!("No cached clusters for {} (offline mode)", region);
+        }
+
         let client = self.get_ecs_client(region);
 
-        let resp = client.list_clusters().send().await?;
+        let resp = with_retry(MAX_ATTEMPTS, || client.list_clusters().send()).await?;
 
         let cluster_arns = resp.cluster_arns();
 
@@ -69,11 +175,13 @@ impl AwsClient {
         }
 
         // Describe clusters to get more details
-        let describe_resp = client
-            .describe_clusters()
-            .set_clusters(Some(cluster_arns.to_vec()))
-            .send()
-            .await?;
+        let describe_resp = with_retry(MAX_ATTEMPTS, || {
+            client
+                .describe_clusters()
+                .set_clusters(Some(cluster_arns.to_vec()))
+                .send()
+        })
+        .await?;
 
         let clusters = describe_resp
             .clusters()
@@ -82,21 +190,31 @@ impl AwsClient {
                 Some(Cluster {
                     arn: c.cluster_arn()?.to_string(),
                     name: c.cluster_name()?.to_string(),
+                    region: region.to_string(),
                 })
             })
             .collect();
 
+        self.cache.put(region, "clusters", "-", &clusters)?;
         Ok(clusters)
     }
 
     pub async fn list_services(&self, region: &str, cluster_arn: &str) -> Result<Vec<Service>> {
+        if let Some(cached) =
+            self.cache.get::<Vec<Service>>(region, "services", cluster_arn, SERVICE_TTL)
+        {
+            return Ok(cached);
+        }
+        if self.offline {
+            bail!("No cached services for {} (offline mode)", cluster_arn);
+        }
+
         let client = self.get_ecs_client(region);
 
-        let resp = client
-            .list_services()
-            .cluster(cluster_arn)
-            .send()
-            .await?;
+        let resp = with_retry(MAX_ATTEMPTS, || {
+            client.list_services().cluster(cluster_arn).send()
+        })
+        .await?;
 
         let service_arns = resp.service_arns();
 
@@ -105,12 +223,14 @@ impl AwsClient {
         }
 
         // Describe services to get more details
-        let describe_resp = client
-            .describe_services()
-            .cluster(cluster_arn)
-            .set_services(Some(service_arns.to_vec()))
-            .send()
-            .await?;
+        let describe_resp = with_retry(MAX_ATTEMPTS, || {
+            client
+                .describe_services()
+                .cluster(cluster_arn)
+                .set_services(Some(service_arns.to_vec()))
+                .send()
+        })
+        .await?;
 
         let services = describe_resp
             .services()
@@ -126,6 +246,7 @@ impl AwsClient {
             })
             .collect();
 
+        self.cache.put(region, "services", cluster_arn, &services)?;
         Ok(services)
     }
 
@@ -135,14 +256,24 @@ impl AwsClient {
         cluster_arn: &str,
         service_name: &str,
     ) -> Result<Vec<Task>> {
+        let parent_key = format!("{}::{}", cluster_arn, service_name);
+        if let Some(cached) = self.cache.get::<Vec<Task>>(region, "tasks", &parent_key, TASK_TTL) {
+            return Ok(cached);
+        }
+        if self.offline {
+            bail!("No cached tasks for {} (offline mode)", parent_key);
+        }
+
         let client = self.get_ecs_client(region);
 
-        let resp = client
-            .list_tasks()
-            .cluster(cluster_arn)
-            .service_name(service_name)
-            .send()
-            .await?;
+        let resp = with_retry(MAX_ATTEMPTS, || {
+            client
+                .list_tasks()
+                .cluster(cluster_arn)
+                .service_name(service_name)
+                .send()
+        })
+        .await?;
 
         let task_arns = resp.task_arns();
 
@@ -151,12 +282,14 @@ impl AwsClient {
         }
 
         // Describe tasks to get more details
-        let describe_resp = client
-            .describe_tasks()
-            .cluster(cluster_arn)
-            .set_tasks(Some(task_arns.to_vec()))
-            .send()
-            .await?;
+        let describe_resp = with_retry(MAX_ATTEMPTS, || {
+            client
+                .describe_tasks()
+                .cluster(cluster_arn)
+                .set_tasks(Some(task_arns.to_vec()))
+                .send()
+        })
+        .await?;
 
         let tasks = describe_resp
             .tasks()
@@ -175,6 +308,7 @@ impl AwsClient {
             })
             .collect();
 
+        self.cache.put(region, "tasks", &parent_key, &tasks)?;
         Ok(tasks)
     }
 
@@ -186,12 +320,14 @@ impl AwsClient {
     ) -> Result<Vec<Container>> {
         let client = self.get_ecs_client(region);
 
-        let describe_resp = client
-            .describe_tasks()
-            .cluster(cluster_arn)
-            .tasks(task_arn)
-            .send()
-            .await?;
+        let describe_resp = with_retry(MAX_ATTEMPTS, || {
+            client
+                .describe_tasks()
+                .cluster(cluster_arn)
+                .tasks(task_arn)
+                .send()
+        })
+        .await?;
 
         let containers = describe_resp
             .tasks()
@@ -214,39 +350,424 @@ impl AwsClient {
         Ok(containers)
     }
 
+    /// Force a new deployment and return the id of the PRIMARY deployment
+    /// it created, so callers can track the rollout with
+    /// [`Self::describe_service_deployment`] instead of firing and forgetting.
     pub async fn force_new_deployment(
         &self,
         region: &str,
         cluster_arn: &str,
         service_name: &str,
+    ) -> Result<String> {
+        let client = self.get_ecs_client(region);
+
+        let resp = with_retry(MAX_ATTEMPTS, || {
+            client
+                .update_service()
+                .cluster(cluster_arn)
+                .service(service_name)
+                .force_new_deployment(true)
+                .send()
+        })
+        .await?;
+
+        let deployment_id = resp
+            .service()
+            .and_then(|s| s.deployments().iter().find(|d| d.status() == Some("PRIMARY")))
+            .and_then(|d| d.id())
+            .context("update_service response did not include a PRIMARY deployment")?
+            .to_string();
+
+        Ok(deployment_id)
+    }
+
+    /// Poll a service's deployments and classify the rollout started by
+    /// [`Self::force_new_deployment`] as in-progress, completed, failed, or
+    /// rolled back - rolled back meaning a newer deployment has replaced
+    /// `started_deployment_id` as PRIMARY before it reached a terminal state.
+    pub async fn describe_service_deployment(
+        &self,
+        region: &str,
+        cluster_arn: &str,
+        service_name: &str,
+        started_deployment_id: &str,
+    ) -> Result<DeploymentStatus> {
+        let client = self.get_ecs_client(region);
+
+        let resp = with_retry(MAX_ATTEMPTS, || {
+            client
+                .describe_services()
+                .cluster(cluster_arn)
+                .services(service_name)
+                .send()
+        })
+        .await?;
+
+        let service = resp
+            .services()
+            .first()
+            .context("Service not found")?;
+
+        let primary = service
+            .deployments()
+            .iter()
+            .find(|d| d.status() == Some("PRIMARY"))
+            .context("Service has no PRIMARY deployment")?;
+
+        let rollout_state = if primary.id() != Some(started_deployment_id) {
+            RolloutState::RolledBack
+        } else {
+            match primary.rollout_state().map(|s| s.as_str()) {
+                Some("COMPLETED") => RolloutState::Completed,
+                Some("FAILED") => RolloutState::Failed,
+                _ => RolloutState::InProgress,
+            }
+        };
+
+        Ok(DeploymentStatus {
+            rollout_state,
+            running: primary.running_count(),
+            desired: primary.desired_count(),
+            pending: primary.pending_count(),
+        })
+    }
+
+    /// Verify an ECS Exec session can actually be started before the TUI
+    /// tries to launch one: `enableExecuteCommand` must be set, the task
+    /// must be RUNNING, and the container must exist on it. This replaces
+    /// finding out after the fact from a cryptic CLI error.
+    pub async fn check_exec_enabled(
+        &self,
+        region: &str,
+        cluster_arn: &str,
+        task_arn: &str,
+        container_name: &str,
     ) -> Result<()> {
         let client = self.get_ecs_client(region);
 
-        client
-            .update_service()
-            .cluster(cluster_arn)
-            .service(service_name)
-            .force_new_deployment(true)
-            .send()
-            .await?;
+        let resp = with_retry(MAX_ATTEMPTS, || {
+            client
+                .describe_tasks()
+                .cluster(cluster_arn)
+                .tasks(task_arn)
+                .send()
+        })
+        .await?;
+
+        let task = resp
+            .tasks()
+            .first()
+            .context("Task not found")?;
+
+        if !task.enable_execute_command() {
+            bail!("ECS Exec is not enabled for this task (enableExecuteCommand=false)");
+        }
+
+        if task.last_status() != Some("RUNNING") {
+            bail!(
+                "Task is not RUNNING (current status: {})",
+                task.last_status().unwrap_or("UNKNOWN")
+            );
+        }
+
+        let container_exists = task
+            .containers()
+            .iter()
+            .any(|c| c.name() == Some(container_name));
+        if !container_exists {
+            bail!("Container '{}' not found in task", container_name);
+        }
 
         Ok(())
     }
 
+    /// Flip a service's `enableExecuteCommand` flag and force a fresh
+    /// deployment so its *next* generation of tasks picks up ECS Exec. Like
+    /// [`Self::force_new_deployment`], the currently running task is
+    /// unaffected - callers should ask the operator to retry once the new
+    /// task is RUNNING.
+    pub async fn enable_exec_command(
+        &self,
+        region: &str,
+        cluster_arn: &str,
+        service_name: &str,
+    ) -> Result<()> {
+        let client = self.get_ecs_client(region);
+
+        with_retry(MAX_ATTEMPTS, || {
+            client
+                .update_service()
+                .cluster(cluster_arn)
+                .service(service_name)
+                .enable_execute_command(true)
+                .force_new_deployment(true)
+                .send()
+        })
+        .await?;
+
+        Ok(())
+    }
+
+    /// Resolve the `awslogs` log group/stream a container writes to, by
+    /// reading its task definition's `logConfiguration`. Returns an error if
+    /// the container uses a different log driver (nothing to tail) or the
+    /// expected `awslogs-group`/`awslogs-stream-prefix` options are absent.
+    pub async fn resolve_container_log_config(
+        &self,
+        region: &str,
+        cluster_arn: &str,
+        task_arn: &str,
+        container_name: &str,
+    ) -> Result<(String, String)> {
+        let client = self.get_ecs_client(region);
+
+        let resp = with_retry(MAX_ATTEMPTS, || {
+            client.describe_tasks().cluster(cluster_arn).tasks(task_arn).send()
+        })
+        .await?;
+
+        let task = resp.tasks().first().context("Task not found")?;
+        let task_definition_arn = task
+            .task_definition_arn()
+            .context("Task has no task definition ARN")?;
+        let task_id = task_arn.split('/').last().unwrap_or(task_arn);
+
+        let td_resp = with_retry(MAX_ATTEMPTS, || {
+            client
+                .describe_task_definition()
+                .task_definition(task_definition_arn)
+                .send()
+        })
+        .await?;
+
+        let container_def = td_resp
+            .task_definition()
+            .and_then(|td| {
+                td.container_definitions()
+                    .iter()
+                    .find(|c| c.name() == Some(container_name))
+            })
+            .context("Container not found in its task definition")?;
+
+        let log_config = container_def
+            .log_configuration()
+            .context("Container has no log configuration")?;
+
+        if log_config.log_driver().as_str() != "awslogs" {
+            bail!(
+                "Container's log driver is '{}', not awslogs",
+                log_config.log_driver().as_str()
+            );
+        }
+
+        let options = log_config.options().cloned().unwrap_or_default();
+        let log_group = options
+            .get("awslogs-group")
+            .context("Log configuration is missing the awslogs-group option")?
+            .clone();
+        let stream_prefix = options
+            .get("awslogs-stream-prefix")
+            .context("Log configuration is missing the awslogs-stream-prefix option")?
+            .clone();
+        let log_stream = format!("{}/{}/{}", stream_prefix, container_name, task_id);
+
+        Ok((log_group, log_stream))
+    }
+
+    /// Fetch any log events at or after `start_time_ms`, paging through
+    /// `FilterLogEvents` until its `nextToken` runs dry. Returns the new
+    /// lines plus the `startTime` the next poll should resume from (one
+    /// millisecond past the newest event seen), so the caller can keep
+    /// advancing the window instead of re-fetching what it already has.
+    pub async fn filter_log_events(
+        &self,
+        region: &str,
+        log_group: &str,
+        log_stream: &str,
+        start_time_ms: i64,
+    ) -> Result<(Vec<String>, i64)> {
+        let client = self.get_logs_client(region);
+
+        let mut lines = Vec::new();
+        let mut next_token: Option<String> = None;
+        let mut next_start_time_ms = start_time_ms;
+
+        loop {
+            let resp = with_retry(MAX_ATTEMPTS, || {
+                client
+                    .filter_log_events()
+                    .log_group_name(log_group)
+                    .log_stream_names(log_stream)
+                    .start_time(start_time_ms)
+                    .set_next_token(next_token.clone())
+                    .send()
+            })
+            .await?;
+
+            for event in resp.events() {
+                if let Some(message) = event.message() {
+                    lines.push(message.to_string());
+                }
+                if let Some(timestamp) = event.timestamp() {
+                    next_start_time_ms = next_start_time_ms.max(timestamp + 1);
+                }
+            }
+
+            next_token = resp.next_token().map(|s| s.to_string());
+            if next_token.is_none() {
+                break;
+            }
+        }
+
+        Ok((lines, next_start_time_ms))
+    }
+
+    /// Start a native SSM Session Manager session against an EC2 instance.
+    /// Unlike the `aws ssm start-session` CLI, this calls `ssm:StartSession`
+    /// directly and hands back the raw [`SessionDescriptor`] - the caller is
+    /// responsible for feeding it to the `session-manager-plugin` binary to
+    /// open the actual interactive stream.
+    pub async fn start_instance_session(
+        &self,
+        region: &str,
+        instance_id: &str,
+    ) -> Result<SessionDescriptor> {
+        let client = self.get_ssm_client(region);
+
+        let resp =
+            with_retry(MAX_ATTEMPTS, || client.start_session().target(instance_id).send())
+                .await?;
+
+        Ok(SessionDescriptor {
+            session_id: resp.session_id().unwrap_or_default().to_string(),
+            stream_url: resp.stream_url().unwrap_or_default().to_string(),
+            token_value: resp.token_value().unwrap_or_default().to_string(),
+            target: instance_id.to_string(),
+            region: region.to_string(),
+        })
+    }
+
+    /// Start an ECS Exec session against a specific container, by calling
+    /// `ecs:ExecuteCommand` directly instead of shelling out to the `aws`
+    /// CLI. `runtime_id` is the value `describe_tasks` reports per-container
+    /// - it's what turns the cluster/task pair into the
+    /// `ecs:<cluster>_<task-id>_<runtime-id>` target Session Manager expects.
+    pub async fn start_container_session(
+        &self,
+        region: &str,
+        cluster_arn: &str,
+        task_arn: &str,
+        container_name: &str,
+        runtime_id: &str,
+        command: &str,
+    ) -> Result<SessionDescriptor> {
+        let client = self.get_ecs_client(region);
+
+        let resp = with_retry(MAX_ATTEMPTS, || {
+            client
+                .execute_command()
+                .cluster(cluster_arn)
+                .task(task_arn)
+                .container(container_name)
+                .interactive(true)
+                .command(command)
+                .send()
+        })
+        .await?;
+
+        let session = resp.session().context("ECS did not return a session")?;
+
+        let cluster_name = cluster_arn.split('/').last().unwrap_or(cluster_arn);
+        let task_id = task_arn.split('/').last().unwrap_or(task_arn);
+
+        Ok(SessionDescriptor {
+            session_id: session.session_id().unwrap_or_default().to_string(),
+            stream_url: session.stream_url().unwrap_or_default().to_string(),
+            token_value: session.token_value().unwrap_or_default().to_string(),
+            target: format!("ecs:{}_{}_{}", cluster_name, task_id, runtime_id),
+            region: region.to_string(),
+        })
+    }
+
     pub async fn list_ec2_instances(&self, region: &str) -> Result<Vec<Ec2Instance>> {
+        self.list_ec2_instances_filtered(region, &[]).await
+    }
+
+    /// Like [`Self::list_ec2_instances`], but narrowed to instances matching
+    /// the given tag/instance-state filters (as configured per-environment
+    /// in `~/.config/ncaws/config.yaml`).
+    pub async fn list_ec2_instances_filtered(
+        &self,
+        region: &str,
+        filters: &[crate::config::InstanceFilter],
+    ) -> Result<Vec<Ec2Instance>> {
+        let parent_key = if filters.is_empty() {
+            "-".to_string()
+        } else {
+            filters
+                .iter()
+                .map(|f| format!("{}={}", f.name, f.values.join(",")))
+                .collect::<Vec<_>>()
+                .join(";")
+        };
+
+        if let Some(cached) =
+            self.cache.get::<Vec<Ec2Instance>>(region, "ec2_instances", &parent_key, EC2_TTL)
+        {
+            return Ok(cached);
+        }
+        if self.offline {
+            bail!("No cached EC2 instances for {} (offline mode)", region);
+        }
+
         let client = self.get_ec2_client(region);
 
-        let resp = client
-            .describe_instances()
-            .send()
-            .await?;
+        let sdk_filters: Vec<aws_sdk_ec2::types::Filter> = filters
+            .iter()
+            .map(|f| {
+                aws_sdk_ec2::types::Filter::builder()
+                    .name(&f.name)
+                    .set_values(Some(f.values.clone()))
+                    .build()
+            })
+            .collect();
+
+        let resp = with_retry(MAX_ATTEMPTS, || {
+            client
+                .describe_instances()
+                .set_filters(if sdk_filters.is_empty() {
+                    None
+                } else {
+                    Some(sdk_filters.clone())
+                })
+                .send()
+        })
+        .await?;
 
         let mut instances = Vec::new();
+        // Group IDs attached to each instance, keyed by instance_id, so they
+        // can be batch-resolved with a single describe_security_groups call
+        // after the main instance loop.
+        let mut instance_group_ids: std::collections::HashMap<String, Vec<String>> =
+            std::collections::HashMap::new();
+        // Volume IDs attached to each instance, keyed by instance_id, so
+        // they can be batch-resolved with a single describe_volumes call
+        // after the main instance loop (mirrors instance_group_ids above).
+        let mut instance_volume_ids_by_instance: std::collections::HashMap<String, Vec<String>> =
+            std::collections::HashMap::new();
 
         for reservation in resp.reservations().iter() {
             for instance in reservation.instances().iter() {
                 let instance_id = instance.instance_id().unwrap_or("N/A").to_string();
 
+                let group_ids: Vec<String> = instance
+                    .security_groups()
+                    .iter()
+                    .filter_map(|g| g.group_id())
+                    .map(|id| id.to_string())
+                    .collect();
+                instance_group_ids.insert(instance_id.clone(), group_ids);
+
                 // Get name from tags
                 let name = instance
                     .tags()
@@ -256,6 +777,12 @@ impl AwsClient {
                     .unwrap_or(&instance_id)
                     .to_string();
 
+                let tags: std::collections::HashMap<String, String> = instance
+                    .tags()
+                    .iter()
+                    .filter_map(|tag| Some((tag.key()?.to_string(), tag.value().unwrap_or("").to_string())))
+                    .collect();
+
                 let instance_type = instance
                     .instance_type()
                     .map(|t| t.as_str().to_string())
@@ -283,6 +810,48 @@ impl AwsClient {
                     .and_then(|p| p.arn())
                     .map(|s| s.to_string());
 
+                let mut instance_volume_ids = Vec::new();
+                let block_devices: Vec<BlockDevice> = instance
+                    .block_device_mappings()
+                    .iter()
+                    .filter_map(|mapping| {
+                        let ebs = mapping.ebs()?;
+                        let volume_id = ebs.volume_id()?.to_string();
+                        instance_volume_ids.push(volume_id.clone());
+                        Some(BlockDevice {
+                            device_name: mapping.device_name().unwrap_or("N/A").to_string(),
+                            volume_id,
+                            // Size/type/encryption aren't in the instance
+                            // mapping itself - filled in by the batch
+                            // describe_volumes call below.
+                            size_gb: 0,
+                            volume_type: "N/A".to_string(),
+                            delete_on_termination: ebs.delete_on_termination().unwrap_or(false),
+                            encrypted: false,
+                        })
+                    })
+                    .collect();
+                instance_volume_ids_by_instance.insert(instance_id.clone(), instance_volume_ids);
+
+                let network_interfaces: Vec<NetworkInterfaceInfo> = instance
+                    .network_interfaces()
+                    .iter()
+                    .map(|eni| NetworkInterfaceInfo {
+                        device_index: eni.attachment().and_then(|a| a.device_index()).unwrap_or(0),
+                        network_interface_id: eni.network_interface_id().unwrap_or("N/A").to_string(),
+                        subnet_id: eni.subnet_id().map(|s| s.to_string()),
+                        private_ip: eni.private_ip_address().map(|s| s.to_string()),
+                        public_ip: eni.association().and_then(|a| a.public_ip()).map(|s| s.to_string()),
+                        mac_address: eni.mac_address().map(|s| s.to_string()),
+                        security_group_ids: eni
+                            .groups()
+                            .iter()
+                            .filter_map(|g| g.group_id())
+                            .map(|id| id.to_string())
+                            .collect(),
+                    })
+                    .collect();
+
                 instances.push(Ec2Instance {
                     instance_id: instance_id.clone(),
                     name,
@@ -294,19 +863,81 @@ impl AwsClient {
                     key_name,
                     iam_instance_profile,
                     ssm_managed: false, // Will be checked separately
+                    security_groups: Vec::new(), // Resolved separately below
+                    block_devices,
+                    network_interfaces,
+                    region: region.to_string(),
+                    tags,
                 });
             }
         }
 
+        // Resolve the distinct security groups referenced by any instance in
+        // one batch call, then fan the results back out per instance.
+        let all_group_ids: Vec<String> = instance_group_ids
+            .values()
+            .flatten()
+            .cloned()
+            .collect::<std::collections::HashSet<_>>()
+            .into_iter()
+            .collect();
+
+        if !all_group_ids.is_empty() {
+            let groups = self.list_security_groups(region, &all_group_ids).await?;
+            let groups_by_id: std::collections::HashMap<&str, &SecurityGroupInfo> =
+                groups.iter().map(|g| (g.group_id.as_str(), g)).collect();
+
+            for instance in &mut instances {
+                if let Some(ids) = instance_group_ids.get(&instance.instance_id) {
+                    instance.security_groups = ids
+                        .iter()
+                        .filter_map(|id| groups_by_id.get(id.as_str()).cloned().cloned())
+                        .collect();
+                }
+            }
+        }
+
+        // Resolve the distinct EBS volumes referenced by any instance's block
+        // device mappings in one batch call, same pattern as security
+        // groups above, to fill in size/type/encryption.
+        let all_volume_ids: Vec<String> = instance_volume_ids_by_instance
+            .values()
+            .flatten()
+            .cloned()
+            .collect::<std::collections::HashSet<_>>()
+            .into_iter()
+            .collect();
+
+        if !all_volume_ids.is_empty() {
+            let volumes_resp = with_retry(MAX_ATTEMPTS, || {
+                client.describe_volumes().set_volume_ids(Some(all_volume_ids.clone())).send()
+            })
+            .await?;
+
+            let volumes_by_id: std::collections::HashMap<&str, &aws_sdk_ec2::types::Volume> = volumes_resp
+                .volumes()
+                .iter()
+                .filter_map(|v| v.volume_id().map(|id| (id, v)))
+                .collect();
+
+            for instance in &mut instances {
+                for device in &mut instance.block_devices {
+                    if let Some(volume) = volumes_by_id.get(device.volume_id.as_str()) {
+                        device.size_gb = volume.size().unwrap_or(0);
+                        device.volume_type = volume.volume_type().map(|t| t.as_str().to_string()).unwrap_or("N/A".to_string());
+                        device.encrypted = volume.encrypted().unwrap_or(false);
+                    }
+                }
+            }
+        }
+
         // Check SSM availability for all instances
         if !instances.is_empty() {
             let ssm_client = self.get_ssm_client(region);
 
             // Check which instances are managed by SSM
-            if let Ok(resp) = ssm_client
-                .describe_instance_information()
-                .send()
-                .await
+            if let Ok(resp) =
+                with_retry(MAX_ATTEMPTS, || ssm_client.describe_instance_information().send()).await
             {
                 let managed_instance_ids: std::collections::HashSet<String> = resp
                     .instance_information_list()
@@ -321,16 +952,120 @@ impl AwsClient {
             }
         }
 
+        self.cache.put(region, "ec2_instances", &parent_key, &instances)?;
         Ok(instances)
     }
 
+    pub async fn start_ec2_instance(&self, region: &str, instance_id: &str) -> Result<()> {
+        let client = self.get_ec2_client(region);
+        with_retry(MAX_ATTEMPTS, || client.start_instances().instance_ids(instance_id).send()).await?;
+        Ok(())
+    }
+
+    pub async fn stop_ec2_instance(&self, region: &str, instance_id: &str) -> Result<()> {
+        let client = self.get_ec2_client(region);
+        with_retry(MAX_ATTEMPTS, || client.stop_instances().instance_ids(instance_id).send()).await?;
+        Ok(())
+    }
+
+    pub async fn reboot_ec2_instance(&self, region: &str, instance_id: &str) -> Result<()> {
+        let client = self.get_ec2_client(region);
+        with_retry(MAX_ATTEMPTS, || client.reboot_instances().instance_ids(instance_id).send()).await?;
+        Ok(())
+    }
+
+    pub async fn terminate_ec2_instance(&self, region: &str, instance_id: &str) -> Result<()> {
+        let client = self.get_ec2_client(region);
+        with_retry(MAX_ATTEMPTS, || client.terminate_instances().instance_ids(instance_id).send()).await?;
+        Ok(())
+    }
+
+    /// Current lifecycle state (`pending`, `running`, `stopping`,
+    /// `stopped`, `shutting-down`, `terminated`, ...) of a single instance,
+    /// for the reconcile loop a lifecycle action kicks off.
+    pub async fn describe_ec2_instance_state(&self, region: &str, instance_id: &str) -> Result<String> {
+        let client = self.get_ec2_client(region);
+        let resp = with_retry(MAX_ATTEMPTS, || {
+            client.describe_instances().instance_ids(instance_id).send()
+        })
+        .await?;
+
+        let state = resp
+            .reservations()
+            .iter()
+            .flat_map(|r| r.instances())
+            .next()
+            .and_then(|i| i.state())
+            .and_then(|s| s.name())
+            .map(|n| n.as_str().to_string())
+            .context("Instance not found")?;
+
+        Ok(state)
+    }
+
+    /// Batch-resolve security groups by ID, collecting the inbound/outbound
+    /// IP-permission ranges alongside the group's name/VPC/description.
+    pub async fn list_security_groups(
+        &self,
+        region: &str,
+        group_ids: &[String],
+    ) -> Result<Vec<SecurityGroupInfo>> {
+        let client = self.get_ec2_client(region);
+
+        let resp = with_retry(MAX_ATTEMPTS, || {
+            client
+                .describe_security_groups()
+                .set_group_ids(Some(group_ids.to_vec()))
+                .send()
+        })
+        .await?;
+
+        let to_permissions = |perms: &[aws_sdk_ec2::types::IpPermission]| -> Vec<IpPermissionInfo> {
+            perms
+                .iter()
+                .map(|perm| IpPermissionInfo {
+                    protocol: perm.ip_protocol().unwrap_or("-1").to_string(),
+                    from_port: perm.from_port(),
+                    to_port: perm.to_port(),
+                    cidrs: perm
+                        .ip_ranges()
+                        .iter()
+                        .filter_map(|r| r.cidr_ip())
+                        .map(|c| c.to_string())
+                        .collect(),
+                })
+                .collect()
+        };
+
+        let groups = resp
+            .security_groups()
+            .iter()
+            .map(|g| SecurityGroupInfo {
+                group_id: g.group_id().unwrap_or("N/A").to_string(),
+                group_name: g.group_name().unwrap_or("N/A").to_string(),
+                vpc_id: g.vpc_id().map(|s| s.to_string()),
+                description: g.description().unwrap_or("").to_string(),
+                inbound: to_permissions(g.ip_permissions()),
+                outbound: to_permissions(g.ip_permissions_egress()),
+            })
+            .collect();
+
+        Ok(groups)
+    }
+
     pub async fn list_rds_clusters(&self, region: &str) -> Result<Vec<RdsCluster>> {
+        if let Some(cached) =
+            self.cache.get::<Vec<RdsCluster>>(region, "rds_clusters", "-", RDS_TTL)
+        {
+            return Ok(cached);
+        }
+        if self.offline {
+            bail!("No cached RDS clusters for {} (offline mode)", region);
+        }
+
         let client = self.get_rds_client(region);
 
-        let resp = client
-            .describe_db_clusters()
-            .send()
-            .await?;
+        let resp = with_retry(MAX_ATTEMPTS, || client.describe_db_clusters().send()).await?;
 
         let clusters = resp
             .db_clusters()
@@ -353,16 +1088,23 @@ impl AwsClient {
             })
             .collect();
 
+        self.cache.put(region, "rds_clusters", "-", &clusters)?;
         Ok(clusters)
     }
 
     pub async fn list_rds_instances(&self, region: &str) -> Result<Vec<RdsInstance>> {
+        if let Some(cached) =
+            self.cache.get::<Vec<RdsInstance>>(region, "rds_instances", "-", RDS_TTL)
+        {
+            return Ok(cached);
+        }
+        if self.offline {
+            bail!("No cached RDS instances for {} (offline mode)", region);
+        }
+
         let client = self.get_rds_client(region);
 
-        let resp = client
-            .describe_db_instances()
-            .send()
-            .await?;
+        let resp = with_retry(MAX_ATTEMPTS, || client.describe_db_instances().send()).await?;
 
         let instances = resp
             .db_instances()
@@ -386,20 +1128,74 @@ impl AwsClient {
             })
             .collect();
 
+        self.cache.put(region, "rds_instances", "-", &instances)?;
         Ok(instances)
     }
 
+    pub async fn start_rds_instance(&self, region: &str, identifier: &str) -> Result<()> {
+        let client = self.get_rds_client(region);
+        with_retry(MAX_ATTEMPTS, || client.start_db_instance().db_instance_identifier(identifier).send())
+            .await?;
+        Ok(())
+    }
+
+    pub async fn stop_rds_instance(&self, region: &str, identifier: &str) -> Result<()> {
+        let client = self.get_rds_client(region);
+        with_retry(MAX_ATTEMPTS, || client.stop_db_instance().db_instance_identifier(identifier).send())
+            .await?;
+        Ok(())
+    }
+
+    pub async fn reboot_rds_instance(&self, region: &str, identifier: &str) -> Result<()> {
+        let client = self.get_rds_client(region);
+        with_retry(MAX_ATTEMPTS, || client.reboot_db_instance().db_instance_identifier(identifier).send())
+            .await?;
+        Ok(())
+    }
+
+    /// Current status (`available`, `starting`, `stopping`, `stopped`, ...)
+    /// of a single RDS instance, for the reconcile loop a lifecycle action
+    /// kicks off.
+    pub async fn describe_rds_instance_status(&self, region: &str, identifier: &str) -> Result<String> {
+        let client = self.get_rds_client(region);
+        let resp = with_retry(MAX_ATTEMPTS, || {
+            client.describe_db_instances().db_instance_identifier(identifier).send()
+        })
+        .await?;
+
+        let status = resp
+            .db_instances()
+            .first()
+            .and_then(|i| i.db_instance_status())
+            .map(|s| s.to_string())
+            .context("DB instance not found")?;
+
+        Ok(status)
+    }
+
     pub async fn list_rds_instances_for_cluster(
         &self,
         region: &str,
         cluster_identifier: &str,
     ) -> Result<Vec<RdsInstance>> {
+        if let Some(cached) = self.cache.get::<Vec<RdsInstance>>(
+            region,
+            "rds_instances",
+            cluster_identifier,
+            RDS_TTL,
+        ) {
+            return Ok(cached);
+        }
+        if self.offline {
+            bail!(
+                "No cached RDS instances for cluster {} (offline mode)",
+                cluster_identifier
+            );
+        }
+
         let client = self.get_rds_client(region);
 
-        let resp = client
-            .describe_db_instances()
-            .send()
-            .await?;
+        let resp = with_retry(MAX_ATTEMPTS, || client.describe_db_instances().send()).await?;
 
         let instances = resp
             .db_instances()
@@ -428,6 +1224,114 @@ impl AwsClient {
             })
             .collect();
 
+        self.cache
+            .put(region, "rds_instances", cluster_identifier, &instances)?;
         Ok(instances)
     }
+
+    /// Run `f` against every region in `regions` concurrently (bounded to
+    /// [`MAX_CONCURRENT_REGIONS`] in flight), tagging each returned item with
+    /// its source region. A region that errors doesn't fail the whole call -
+    /// its error is collected separately so the caller still gets partial
+    /// results from the regions that succeeded.
+    async fn fan_out<T, Fut, F>(
+        &self,
+        regions: &[String],
+        f: F,
+    ) -> (Vec<(String, T)>, Vec<(String, AwsError)>)
+    where
+        F: Fn(AwsClient, String) -> Fut,
+        Fut: std::future::Future<Output = Result<Vec<T>>>,
+    {
+        let results: Vec<(String, Result<Vec<T>>)> = stream::iter(regions.iter().cloned())
+            .map(|region| {
+                let client = self.clone();
+                let fut = f(client, region.clone());
+                async move { (region, fut.await) }
+            })
+            .buffer_unordered(MAX_CONCURRENT_REGIONS)
+            .collect()
+            .await;
+
+        let mut items = Vec::new();
+        let mut errors = Vec::new();
+
+        for (region, result) in results {
+            match result {
+                Ok(found) => items.extend(found.into_iter().map(|item| (region.clone(), item))),
+                Err(e) => {
+                    let classified = e.downcast::<AwsError>().unwrap_or_else(|e| AwsError::Other {
+                        code: "Unknown".to_string(),
+                        message: e.to_string(),
+                    });
+                    errors.push((region, classified));
+                }
+            }
+        }
+
+        (items, errors)
+    }
+
+    /// List ECS clusters across every region in `regions` concurrently,
+    /// tagging each cluster with its source region so the TUI can present a
+    /// single global inventory instead of N sequential per-region fetches.
+    pub async fn list_all_clusters(
+        &self,
+        regions: &[String],
+    ) -> (Vec<(String, Cluster)>, Vec<(String, AwsError)>) {
+        self.fan_out(regions, |client, region| async move {
+            client.list_clusters(&region).await
+        })
+        .await
+    }
+
+    /// Like [`Self::list_all_clusters`], but for EC2 instances.
+    pub async fn list_all_ec2_instances(
+        &self,
+        regions: &[String],
+    ) -> (Vec<(String, Ec2Instance)>, Vec<(String, AwsError)>) {
+        self.fan_out(regions, |client, region| async move {
+            client.list_ec2_instances(&region).await
+        })
+        .await
+    }
+
+    /// Discover the regions actually enabled for this account via EC2
+    /// `DescribeRegions` (`all_regions(false)`, so opted-out regions are
+    /// excluded), instead of relying on the hard-coded list `App::new` falls
+    /// back to. The partition is inferred from the region name prefix, the
+    /// same signal the SDK's own endpoint resolver uses.
+    pub async fn list_regions(&self) -> Result<Vec<Region>> {
+        // DescribeRegions is a partition-wide listing, not specific to any
+        // one region, so any enabled region's endpoint will do.
+        let client = self.get_ec2_client("us-east-1");
+
+        let resp = with_retry(MAX_ATTEMPTS, || {
+            client.describe_regions().all_regions(false).send()
+        })
+        .await?;
+
+        let regions = resp
+            .regions()
+            .iter()
+            .filter_map(|r| {
+                let name = r.region_name()?.to_string();
+                let partition = Some(partition_for_region(&name));
+                Some(Region { name, partition })
+            })
+            .collect();
+
+        Ok(regions)
+    }
+}
+
+/// Infer the AWS partition from a region name's prefix.
+fn partition_for_region(region: &str) -> String {
+    if region.starts_with("cn-") {
+        "aws-cn".to_string()
+    } else if region.starts_with("us-gov-") {
+        "aws-us-gov".to_string()
+    } else {
+        "aws".to_string()
+    }
 }
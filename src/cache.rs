@@ -0,0 +1,121 @@
+//! Local SQLite cache for AWS inventory listings.
+//!
+//! Every navigation step used to re-hit the AWS APIs, which is slow and
+//! easy to rate-limit across many accounts/regions. `InventoryCache` persists
+//! each listing as JSON, keyed by `(region, resource_type, parent_key)`
+//! alongside a fetched-at timestamp, so `AwsClient` can serve a recent
+//! listing straight from disk instead of the network - and so `--offline`
+//! mode has something to show even when AWS is unreachable.
+
+use anyhow::{Context, Result};
+use rusqlite::{params, Connection};
+use serde::{de::DeserializeOwned, Serialize};
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub struct InventoryCache {
+    conn: Mutex<Connection>,
+}
+
+impl InventoryCache {
+    /// Open (creating if necessary) the cache database at
+    /// `~/.config/ncaws/cache.sqlite3`.
+    pub fn open() -> Result<Self> {
+        let path = Self::path()?;
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+
+        let conn = Connection::open(&path)
+            .with_context(|| format!("Failed to open cache database at {}", path.display()))?;
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS cache_entries (
+                region        TEXT NOT NULL,
+                resource_type TEXT NOT NULL,
+                parent_key    TEXT NOT NULL,
+                payload       TEXT NOT NULL,
+                fetched_at    INTEGER NOT NULL,
+                PRIMARY KEY (region, resource_type, parent_key)
+            )",
+        )?;
+
+        Ok(Self {
+            conn: Mutex::new(conn),
+        })
+    }
+
+    fn path() -> Result<PathBuf> {
+        let home = std::env::var("HOME").context("HOME is not set")?;
+        Ok(PathBuf::from(home)
+            .join(".config")
+            .join("ncaws")
+            .join("cache.sqlite3"))
+    }
+
+    /// Return the cached value for this key if it's younger than `ttl`.
+    pub fn get<T: DeserializeOwned>(
+        &self,
+        region: &str,
+        resource_type: &str,
+        parent_key: &str,
+        ttl: Duration,
+    ) -> Option<T> {
+        let conn = self.conn.lock().ok()?;
+        let row: Option<(String, i64)> = conn
+            .query_row(
+                "SELECT payload, fetched_at FROM cache_entries
+                 WHERE region = ?1 AND resource_type = ?2 AND parent_key = ?3",
+                params![region, resource_type, parent_key],
+                |r| Ok((r.get(0)?, r.get(1)?)),
+            )
+            .ok();
+
+        let (payload, fetched_at) = row?;
+        let age_secs = now_secs().saturating_sub(fetched_at.max(0) as u64);
+        if age_secs > ttl.as_secs() {
+            return None;
+        }
+
+        serde_json::from_str(&payload).ok()
+    }
+
+    /// Upsert a value into the cache, stamped with the current time.
+    pub fn put<T: Serialize>(
+        &self,
+        region: &str,
+        resource_type: &str,
+        parent_key: &str,
+        value: &T,
+    ) -> Result<()> {
+        let payload = serde_json::to_string(value)?;
+        let conn = self.conn.lock().unwrap();
+        conn.execute(
+            "INSERT INTO cache_entries (region, resource_type, parent_key, payload, fetched_at)
+             VALUES (?1, ?2, ?3, ?4, ?5)
+             ON CONFLICT(region, resource_type, parent_key)
+             DO UPDATE SET payload = excluded.payload, fetched_at = excluded.fetched_at",
+            params![region, resource_type, parent_key, payload, now_secs() as i64],
+        )?;
+        Ok(())
+    }
+
+    /// Drop cached rows for a resource type, forcing the next read to
+    /// refetch from AWS. Used by the explicit 'r' refresh binding.
+    pub fn invalidate(&self, region: &str, resource_type: &str) -> Result<()> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute(
+            "DELETE FROM cache_entries WHERE region = ?1 AND resource_type = ?2",
+            params![region, resource_type],
+        )?;
+        Ok(())
+    }
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
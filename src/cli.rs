@@ -0,0 +1,240 @@
+//! Non-interactive `list` subcommand, so `ncaws` can be scripted (e.g. from
+//! CI) without going through the TUI. Reuses `AwsClient` and the same
+//! `Cluster`/`Service`/`Task`/`Container`/`Ec2Instance` structs the TUI
+//! renders - this just prints them instead of drawing them.
+
+use anyhow::{bail, Context, Result};
+
+use crate::aws::AwsClient;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Json,
+    Table,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Resource {
+    Clusters,
+    Services,
+    Tasks,
+    Containers,
+    Ec2Instances,
+}
+
+pub struct ListArgs {
+    pub resource: Resource,
+    pub region: String,
+    pub cluster: Option<String>,
+    pub service: Option<String>,
+    pub task: Option<String>,
+    pub format: Format,
+}
+
+/// Parse `ncaws list <resource> --region <region> [--cluster ...] [--service ...] [--task ...] [--format json|table]`.
+/// Returns `Ok(None)` if `args` isn't a `list` invocation at all, so `main`
+/// can fall through to the interactive TUI.
+pub fn parse(args: &[String]) -> Result<Option<ListArgs>> {
+    if args.first().map(String::as_str) != Some("list") {
+        return Ok(None);
+    }
+
+    let resource = match args.get(1).map(String::as_str) {
+        Some("clusters") => Resource::Clusters,
+        Some("services") => Resource::Services,
+        Some("tasks") => Resource::Tasks,
+        Some("containers") => Resource::Containers,
+        Some("ec2-instances") => Resource::Ec2Instances,
+        Some(other) => bail!(
+            "Unknown resource '{}' (expected clusters, services, tasks, containers, or ec2-instances)",
+            other
+        ),
+        None => bail!(
+            "Usage: ncaws list <clusters|services|tasks|containers|ec2-instances> --region <region> [--cluster <arn>] [--service <name>] [--task <arn>] [--format json|table]"
+        ),
+    };
+
+    let mut region = None;
+    let mut cluster = None;
+    let mut service = None;
+    let mut task = None;
+    let mut format = Format::Table;
+
+    let mut rest = args[2..].iter();
+    while let Some(flag) = rest.next() {
+        let value = rest
+            .next()
+            .with_context(|| format!("Missing value for {}", flag))?;
+        match flag.as_str() {
+            "--region" => region = Some(value.clone()),
+            "--cluster" => cluster = Some(value.clone()),
+            "--service" => service = Some(value.clone()),
+            "--task" => task = Some(value.clone()),
+            "--format" => {
+                format = match value.as_str() {
+                    "json" => Format::Json,
+                    "table" => Format::Table,
+                    other => bail!("Unknown format '{}' (expected json or table)", other),
+                }
+            }
+            other => bail!("Unknown flag '{}'", other),
+        }
+    }
+
+    let region = region.context("--region is required")?;
+
+    if matches!(resource, Resource::Services | Resource::Tasks | Resource::Containers) && cluster.is_none() {
+        bail!("--cluster is required for '{}'", resource_name(resource));
+    }
+    if resource == Resource::Tasks && service.is_none() {
+        bail!("--service is required for 'tasks'");
+    }
+    if resource == Resource::Containers && task.is_none() {
+        bail!("--task is required for 'containers'");
+    }
+
+    Ok(Some(ListArgs {
+        resource,
+        region,
+        cluster,
+        service,
+        task,
+        format,
+    }))
+}
+
+fn resource_name(resource: Resource) -> &'static str {
+    match resource {
+        Resource::Clusters => "clusters",
+        Resource::Services => "services",
+        Resource::Tasks => "tasks",
+        Resource::Containers => "containers",
+        Resource::Ec2Instances => "ec2-instances",
+    }
+}
+
+/// Load the requested resource level with `client` and print it to stdout in
+/// `args.format`. The same loader functions the TUI spawns in the background
+/// back this call, just awaited directly instead of routed through
+/// `AppEvent`.
+pub async fn run(client: &AwsClient, args: ListArgs) -> Result<()> {
+    match args.resource {
+        Resource::Clusters => {
+            let clusters = client.list_clusters(&args.region).await?;
+            print_json_or_table(
+                args.format,
+                &clusters,
+                &["name", "arn"],
+                |c| vec![c.name.clone(), c.arn.clone()],
+            )
+        }
+        Resource::Services => {
+            let cluster = args.cluster.as_deref().expect("validated in parse");
+            let services = client.list_services(&args.region, cluster).await?;
+            print_json_or_table(
+                args.format,
+                &services,
+                &["name", "status", "desired", "running"],
+                |s| {
+                    vec![
+                        s.name.clone(),
+                        s.status.clone(),
+                        s.desired_count.to_string(),
+                        s.running_count.to_string(),
+                    ]
+                },
+            )
+        }
+        Resource::Tasks => {
+            let cluster = args.cluster.as_deref().expect("validated in parse");
+            let service = args.service.as_deref().expect("validated in parse");
+            let tasks = client.list_tasks(&args.region, cluster, service).await?;
+            print_json_or_table(
+                args.format,
+                &tasks,
+                &["task_id", "status", "cpu", "memory"],
+                |t| vec![t.task_id.clone(), t.status.clone(), t.cpu.clone(), t.memory.clone()],
+            )
+        }
+        Resource::Containers => {
+            let cluster = args.cluster.as_deref().expect("validated in parse");
+            let task = args.task.as_deref().expect("validated in parse");
+            let containers = client.list_containers(&args.region, cluster, task).await?;
+            print_json_or_table(
+                args.format,
+                &containers,
+                &["name", "status", "image", "runtime_id"],
+                |c| {
+                    vec![
+                        c.name.clone(),
+                        c.status.clone(),
+                        c.image.clone(),
+                        c.runtime_id.clone().unwrap_or_default(),
+                    ]
+                },
+            )
+        }
+        Resource::Ec2Instances => {
+            let instances = client.list_ec2_instances(&args.region).await?;
+            print_json_or_table(
+                args.format,
+                &instances,
+                &["instance_id", "name", "state", "instance_type", "private_ip"],
+                |i| {
+                    vec![
+                        i.instance_id.clone(),
+                        i.name.clone(),
+                        i.state.clone(),
+                        i.instance_type.clone(),
+                        i.private_ip.clone().unwrap_or_default(),
+                    ]
+                },
+            )
+        }
+    }
+}
+
+fn print_json_or_table<T: serde::Serialize>(
+    format: Format,
+    rows: &[T],
+    headers: &[&str],
+    to_row: impl Fn(&T) -> Vec<String>,
+) -> Result<()> {
+    match format {
+        Format::Json => {
+            println!("{}", serde_json::to_string_pretty(rows)?);
+        }
+        Format::Table => {
+            let rows: Vec<Vec<String>> = rows.iter().map(to_row).collect();
+            print_table(headers, &rows);
+        }
+    }
+    Ok(())
+}
+
+/// A minimal fixed-width table printer - no external table-formatting crate
+/// to reach for without a manifest, so columns are just sized to their
+/// widest cell.
+fn print_table(headers: &[&str], rows: &[Vec<String>]) {
+    let mut widths: Vec<usize> = headers.iter().map(|h| h.len()).collect();
+    for row in rows {
+        for (i, cell) in row.iter().enumerate() {
+            widths[i] = widths[i].max(cell.len());
+        }
+    }
+
+    let print_row = |cells: &[String]| {
+        let line: Vec<String> = cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect();
+        println!("{}", line.join("  "));
+    };
+
+    print_row(&headers.iter().map(|h| h.to_string()).collect::<Vec<_>>());
+    print_row(&widths.iter().map(|w| "-".repeat(*w)).collect::<Vec<_>>());
+    for row in rows {
+        print_row(row);
+    }
+}
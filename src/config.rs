@@ -0,0 +1,95 @@
+//! YAML environment/profile configuration, loaded from
+//! `~/.config/ncaws/config.yaml`.
+//!
+//! Each named environment mirrors the layout used by aws_ec2_environment
+//! style tooling: a region, SSH connection defaults, an SSM preference, and
+//! EC2 instance filters, so operators don't have to re-enter the same
+//! details every time they switch accounts or regions.
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct InstanceFilter {
+    pub name: String,
+    pub values: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct Environment {
+    pub aws_region: String,
+    pub ssh_user: Option<String>,
+    pub ssh_key_path: Option<String>,
+    #[serde(default)]
+    pub use_ssm: bool,
+    #[serde(default)]
+    pub filters: Vec<InstanceFilter>,
+    /// Default command to run for ECS Exec sessions, e.g. `/bin/bash` or a
+    /// one-shot `whoami`. Falls back to `/bin/sh` when unset.
+    pub exec_command: Option<String>,
+    /// User to run the ECS Exec command as, via `su`, since `execute-command`
+    /// always connects as root.
+    pub exec_user: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct Config {
+    #[serde(default)]
+    pub environments: HashMap<String, Environment>,
+}
+
+impl Config {
+    /// Load the user's config file, or fall back to an empty `Config` (not
+    /// an error) when it doesn't exist so ncaws keeps working unconfigured.
+    pub fn load() -> Result<Self> {
+        let path = Self::path()?;
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let contents = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        serde_yaml::from_str(&contents)
+            .with_context(|| format!("Failed to parse {}", path.display()))
+    }
+
+    fn path() -> Result<PathBuf> {
+        let home = std::env::var("HOME").context("HOME is not set")?;
+        Ok(PathBuf::from(home)
+            .join(".config")
+            .join("ncaws")
+            .join("config.yaml"))
+    }
+}
+
+/// List the named profiles declared in `~/.aws/config`, so sessions can be
+/// launched under an assume-role or MFA-gated profile without the user
+/// hand-editing `AWS_PROFILE` first.
+///
+/// Returns an empty list (not an error) when the file doesn't exist.
+pub fn list_aws_profiles() -> Result<Vec<String>> {
+    let home = std::env::var("HOME").context("HOME is not set")?;
+    let path = PathBuf::from(home).join(".aws").join("config");
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let contents =
+        std::fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))?;
+
+    let mut profiles = Vec::new();
+    for line in contents.lines() {
+        let line = line.trim();
+        if let Some(header) = line.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+            if header == "default" {
+                profiles.push("default".to_string());
+            } else if let Some(name) = header.strip_prefix("profile ") {
+                profiles.push(name.trim().to_string());
+            }
+        }
+    }
+
+    Ok(profiles)
+}
@@ -0,0 +1,130 @@
+//! Typed classification of AWS SDK errors, plus a retry-with-backoff wrapper.
+//!
+//! Every `aws.rs` call used to propagate errors with a bare `?` into
+//! `anyhow::Result`, so the UI couldn't tell throttling apart from
+//! access-denied or a genuinely empty account. [`AwsError::classify`] reads
+//! the service error's code/message and buckets it into a variant the app
+//! layer can render differently, and [`with_retry`] wraps the retryable ones
+//! in exponential backoff with jitter so transient rate limiting self-heals
+//! instead of surfacing as a fatal error.
+
+use aws_smithy_runtime_api::client::result::SdkError;
+use aws_smithy_types::error::metadata::ProvideErrorMetadata;
+use std::fmt;
+use std::future::Future;
+use std::time::Duration;
+
+/// An AWS API error, classified by service error code.
+#[derive(Debug, Clone)]
+pub enum AwsError {
+    /// Request was throttled (`ThrottlingException`, `RequestLimitExceeded`,
+    /// ...). Retried automatically by [`with_retry`].
+    Throttled { code: String, message: String },
+    /// Caller lacks permission for the operation.
+    AccessDenied { code: String, message: String },
+    /// The referenced resource (cluster, service, instance, ...) is gone.
+    NotFound { code: String, message: String },
+    /// Anything else, surfaced verbatim.
+    Other { code: String, message: String },
+}
+
+impl AwsError {
+    /// Classify any error exposing AWS service error metadata (service
+    /// error enums and the `SdkError` wrapper around them both qualify).
+    pub fn classify<E: ProvideErrorMetadata>(err: &E) -> Self {
+        let code = err.code().unwrap_or("Unknown").to_string();
+        let message = err.message().unwrap_or("").to_string();
+
+        match code.as_str() {
+            "ThrottlingException"
+            | "RequestLimitExceeded"
+            | "TooManyRequestsException"
+            | "ProvisionedThroughputExceededException" => AwsError::Throttled { code, message },
+
+            "AccessDeniedException" | "AccessDenied" | "UnauthorizedException" => {
+                AwsError::AccessDenied { code, message }
+            }
+
+            "ClusterNotFoundException"
+            | "ServiceNotFoundException"
+            | "ResourceNotFoundException"
+            | "InvalidInstanceID.NotFound"
+            | "DBClusterNotFoundFault"
+            | "DBInstanceNotFoundFault" => AwsError::NotFound { code, message },
+
+            _ => AwsError::Other { code, message },
+        }
+    }
+
+    /// Whether this error is worth retrying with backoff.
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, AwsError::Throttled { .. })
+    }
+}
+
+impl fmt::Display for AwsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AwsError::Throttled { code, message } => {
+                write!(f, "Throttled by AWS ({}): {}", code, message)
+            }
+            AwsError::AccessDenied { code, message } => {
+                write!(f, "Access denied ({}): {}", code, message)
+            }
+            AwsError::NotFound { code, message } => write!(f, "Not found ({}): {}", code, message),
+            AwsError::Other { code, message } => write!(f, "{}: {}", code, message),
+        }
+    }
+}
+
+impl std::error::Error for AwsError {}
+
+const BASE_DELAY: Duration = Duration::from_millis(200);
+const MAX_DELAY: Duration = Duration::from_secs(5);
+
+/// Exponential backoff with jitter: doubles `BASE_DELAY` per attempt, capped
+/// at `MAX_DELAY`, plus up to 20% random jitter so concurrent callers (e.g. a
+/// multi-region fan-out) don't all retry in lockstep.
+fn backoff_delay(attempt: u32) -> Duration {
+    let exp = BASE_DELAY.saturating_mul(1u32.checked_shl(attempt).unwrap_or(u32::MAX));
+    let capped = exp.min(MAX_DELAY);
+    let jitter = Duration::from_millis((capped.as_millis() as f64 * 0.2 * jitter_fraction()) as u64);
+    capped + jitter
+}
+
+/// A cheap, non-cryptographic source of jitter; good enough to desynchronize
+/// retries without pulling in a `rand` dependency.
+fn jitter_fraction() -> f64 {
+    use std::time::{SystemTime, UNIX_EPOCH};
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.subsec_nanos())
+        .unwrap_or(0);
+    (nanos % 1000) as f64 / 1000.0
+}
+
+/// Retry an AWS SDK call on retryable (throttling) errors, with exponential
+/// backoff and jitter, up to `max_attempts` total tries.
+pub async fn with_retry<T, E, R, F, Fut>(max_attempts: u32, mut call: F) -> Result<T, AwsError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, SdkError<E, R>>>,
+    E: ProvideErrorMetadata,
+{
+    let mut attempt = 0;
+
+    loop {
+        match call().await {
+            Ok(value) => return Ok(value),
+            Err(err) => {
+                let classified = AwsError::classify(&err);
+                attempt += 1;
+                if classified.is_retryable() && attempt < max_attempts {
+                    tokio::time::sleep(backoff_delay(attempt)).await;
+                    continue;
+                }
+                return Err(classified);
+            }
+        }
+    }
+}
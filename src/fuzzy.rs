@@ -0,0 +1,99 @@
+//! Fuzzy subsequence matching for the list filter bar ('/').
+//!
+//! A candidate matches a pattern if every pattern character appears in it,
+//! in order, as a (possibly non-contiguous) subsequence. Matches are scored
+//! fzf-style: a small bonus for landing on a word boundary, a larger bonus
+//! for runs of consecutive matched characters, and a penalty for each
+//! character skipped between one match and the next. Candidates that don't
+//! match at all, or whose best match scores zero or below, are dropped by
+//! [`filter_and_sort`].
+
+const WORD_BOUNDARY_BONUS: i64 = 10;
+const CONSECUTIVE_BONUS: i64 = 15;
+const GAP_PENALTY: i64 = 2;
+
+/// A single candidate's match: its score and the char indices (into the
+/// original, not lower-cased, candidate) that matched the pattern, for
+/// bolding in the rendered `Span`s.
+pub struct FuzzyMatch {
+    pub score: i64,
+    pub matched_indices: Vec<usize>,
+}
+
+/// Match `pattern` against `candidate` case-insensitively, returning the
+/// leftmost greedy subsequence alignment, or `None` if `pattern` isn't a
+/// subsequence of `candidate` at all or that alignment scores zero or
+/// below. An empty pattern matches everything
+/// with a zero score and no highlighted characters.
+pub fn fuzzy_match(pattern: &str, candidate: &str) -> Option<FuzzyMatch> {
+    if pattern.is_empty() {
+        return Some(FuzzyMatch {
+            score: 0,
+            matched_indices: Vec::new(),
+        });
+    }
+
+    let pattern_lower: Vec<char> = pattern.chars().flat_map(char::to_lowercase).collect();
+    let candidate_chars: Vec<char> = candidate.chars().collect();
+    let candidate_lower: Vec<char> = candidate_chars.iter().flat_map(|c| c.to_lowercase()).collect();
+
+    let mut matched_indices = Vec::with_capacity(pattern_lower.len());
+    let mut cursor = 0;
+    for &pc in &pattern_lower {
+        let offset = candidate_lower[cursor..].iter().position(|&cc| cc == pc)?;
+        matched_indices.push(cursor + offset);
+        cursor += offset + 1;
+    }
+
+    let score = score_match(&candidate_chars, &matched_indices);
+    if score <= 0 {
+        return None;
+    }
+
+    Some(FuzzyMatch { score, matched_indices })
+}
+
+/// Run every candidate through [`fuzzy_match`], keep the ones that matched,
+/// and sort by descending score. Returns `(original_index, score,
+/// matched_indices)` so callers can both remap a selection and bold the
+/// matched characters.
+pub fn filter_and_sort(pattern: &str, candidates: &[String]) -> Vec<(usize, i64, Vec<usize>)> {
+    let mut scored: Vec<(usize, i64, Vec<usize>)> = candidates
+        .iter()
+        .enumerate()
+        .filter_map(|(i, candidate)| fuzzy_match(pattern, candidate).map(|m| (i, m.score, m.matched_indices)))
+        .collect();
+
+    scored.sort_by(|a, b| b.1.cmp(&a.1));
+    scored
+}
+
+fn is_word_boundary(candidate: &[char], idx: usize) -> bool {
+    if idx == 0 {
+        return true;
+    }
+    matches!(candidate[idx - 1], '/' | '-' | '_' | ' ' | '.' | ':')
+}
+
+fn score_match(candidate: &[char], matched_indices: &[usize]) -> i64 {
+    let mut score = 0i64;
+
+    for (pos, &idx) in matched_indices.iter().enumerate() {
+        score += 1;
+
+        if is_word_boundary(candidate, idx) {
+            score += WORD_BOUNDARY_BONUS;
+        }
+
+        if pos > 0 {
+            let prev_idx = matched_indices[pos - 1];
+            if idx == prev_idx + 1 {
+                score += CONSECUTIVE_BONUS;
+            } else {
+                score -= GAP_PENALTY * (idx - prev_idx - 1) as i64;
+            }
+        }
+    }
+
+    score
+}
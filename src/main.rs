@@ -1,7 +1,15 @@
 mod app;
 mod aws;
+mod cache;
+mod cli;
+mod config;
+mod errors;
+mod fuzzy;
+mod theme;
 mod ui;
 mod terminal;
+mod preview;
+mod pty;
 
 use anyhow::Result;
 use crossterm::{
@@ -20,6 +28,26 @@ use app::{App, AppEvent};
 
 #[tokio::main]
 async fn main() -> Result<()> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    // `ncaws list ...` is a headless, scriptable path that never touches the
+    // terminal - run it and exit instead of starting the TUI.
+    if let Some(list_args) = cli::parse(&args).unwrap_or_else(|e| {
+        eprintln!("Error: {:?}", e);
+        std::process::exit(1);
+    }) {
+        let client = aws::AwsClient::new().await?;
+        if let Err(e) = cli::run(&client, list_args).await {
+            eprintln!("Error: {:?}", e);
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
+    // Only flag we recognize so far: serve inventory purely from the local
+    // cache instead of hitting AWS at all.
+    let offline = args.iter().any(|arg| arg == "--offline");
+
     // Setup terminal
     enable_raw_mode()?;
     let mut stdout = io::stdout();
@@ -27,15 +55,44 @@ async fn main() -> Result<()> {
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
-    // Create app
-    let mut app = App::new().await?;
-
     // Event channel
     let (tx, mut rx) = mpsc::channel::<AppEvent>(100);
 
+    // Create app. App::new spawns the auto-refresh ticker on `tx`, so the
+    // channel has to exist first.
+    let mut app = App::new(offline, tx.clone()).await?;
+
+    // Kick off region discovery in the background; App::new already left a
+    // hard-coded fallback list in place so the first frame isn't empty.
+    {
+        let client = app.aws_client.clone();
+        let tx = tx.clone();
+        let op_id = app.start_operation("Discovering regions");
+        tokio::spawn(async move {
+            match client.list_regions().await {
+                Ok(regions) => {
+                    let _ = tx.send(AppEvent::RegionsLoaded { regions, op_id }).await;
+                }
+                Err(e) => {
+                    let _ = tx
+                        .send(AppEvent::Error {
+                            message: format!("Failed to discover regions, using defaults: {}", e),
+                            op_id: Some(op_id),
+                        })
+                        .await;
+                }
+            }
+        });
+    }
+
     // Run app
     let res = run_app(&mut terminal, &mut app, &mut rx, tx).await;
 
+    // Make sure no SSM port-forwarding tunnels or embedded shells are left
+    // running behind us.
+    app.stop_all_port_forward_sessions();
+    app.close_shell();
+
     // Restore terminal
     disable_raw_mode()?;
     execute!(
@@ -65,34 +122,150 @@ async fn run_app(
         if event::poll(std::time::Duration::from_millis(100))? {
             if let Event::Key(key) = event::read()? {
                 if key.kind == KeyEventKind::Press {
-                    match key.code {
-                        KeyCode::Char('q') => {
-                            if app.can_quit() {
-                                return Ok(());
-                            }
+                    if app.active_shell.is_some() {
+                        // Close the shell pane, killing its process, and return to the console.
+                        if key.code == KeyCode::F(10) {
+                            app.close_shell();
+                        } else if let Some(bytes) = key_event_to_pty_bytes(key.code) {
+                            app.send_shell_input(&bytes)?;
                         }
-                        KeyCode::Char('r') => {
-                            let tx = tx.clone();
-                            app.refresh(tx).await?;
+                    } else if app.show_log_popup {
+                        match key.code {
+                            KeyCode::Down | KeyCode::Char('j') => app.log_scroll_down(),
+                            KeyCode::Up | KeyCode::Char('k') => app.log_scroll_up(),
+                            KeyCode::Char('f') => app.toggle_log_follow(),
+                            KeyCode::Char('L') | KeyCode::Esc => app.close_log_popup(),
+                            _ => {}
                         }
-                        KeyCode::Down | KeyCode::Char('j') => {
-                            app.next_item();
+                    } else if app.show_info_popup {
+                        match key.code {
+                            KeyCode::Down | KeyCode::Char('j') => app.info_popup_scroll_down(),
+                            KeyCode::Up | KeyCode::Char('k') => app.info_popup_scroll_up(),
+                            KeyCode::PageDown => app.info_popup_scroll_page_down(),
+                            KeyCode::PageUp => app.info_popup_scroll_page_up(),
+                            KeyCode::Char('i') | KeyCode::Esc => app.close_info_popup(),
+                            _ => {}
                         }
-                        KeyCode::Up | KeyCode::Char('k') => {
-                            app.previous_item();
+                    } else if app.show_iac_popup {
+                        match key.code {
+                            KeyCode::Char('c') => app.cycle_iac_format(),
+                            KeyCode::Char('w') => app.write_iac_export()?,
+                            KeyCode::Char('I') | KeyCode::Esc => app.close_iac_popup(),
+                            _ => {}
                         }
-                        KeyCode::Enter => {
-                            let tx = tx.clone();
-                            app.select_item(tx).await?;
+                    } else if app.show_context_menu {
+                        match key.code {
+                            KeyCode::Down | KeyCode::Char('j') => app.context_menu_next(),
+                            KeyCode::Up | KeyCode::Char('k') => app.context_menu_previous(),
+                            KeyCode::Enter => {
+                                let tx = tx.clone();
+                                app.invoke_context_action(tx).await?;
+                            }
+                            KeyCode::Esc | KeyCode::Char('m') => app.close_context_menu(),
+                            _ => {}
                         }
-                        KeyCode::Esc | KeyCode::Backspace => {
-                            app.go_back();
+                    } else if app.filter_mode {
+                        // While editing the filter, Up/Down still move through
+                        // the narrowed list so the user can see matches land
+                        // without leaving the input.
+                        match key.code {
+                            KeyCode::Down => app.next_item(),
+                            KeyCode::Up => app.previous_item(),
+                            KeyCode::Char(c) => app.filter_push_char(c),
+                            KeyCode::Backspace => app.filter_pop_char(),
+                            KeyCode::Enter => {
+                                app.confirm_filter();
+                                let tx = tx.clone();
+                                app.select_item(tx).await?;
+                            }
+                            KeyCode::Esc => app.clear_filter(),
+                            _ => {}
                         }
-                        KeyCode::Char('e') | KeyCode::Char('s') => {
-                            // Execute command on container (e) or SSH to EC2 (s)
-                            app.execute_command().await?;
+                    } else {
+                        match key.code {
+                            KeyCode::Char('q') => {
+                                if app.can_quit() {
+                                    return Ok(());
+                                }
+                            }
+                            KeyCode::Char('r') => {
+                                let tx = tx.clone();
+                                app.refresh(tx).await?;
+                            }
+                            KeyCode::Down | KeyCode::Char('j') => {
+                                app.next_item();
+                            }
+                            KeyCode::Up | KeyCode::Char('k') => {
+                                app.previous_item();
+                            }
+                            KeyCode::Enter => {
+                                let tx = tx.clone();
+                                app.select_item(tx).await?;
+                            }
+                            KeyCode::Esc | KeyCode::Backspace => {
+                                app.go_back();
+                            }
+                            KeyCode::Char('e') | KeyCode::Char('s') => {
+                                // Execute command on container (e) or SSH to EC2 (s)
+                                app.execute_command().await?;
+                            }
+                            KeyCode::Char('p') => {
+                                app.start_port_forward().await?;
+                            }
+                            KeyCode::Char('t') => {
+                                app.toggle_port_forward_panel();
+                            }
+                            KeyCode::Char('v') => {
+                                app.cycle_environment();
+                            }
+                            KeyCode::Char('a') => {
+                                app.cycle_aws_profile();
+                            }
+                            KeyCode::Char('l') => {
+                                app.toggle_auto_refresh();
+                            }
+                            KeyCode::Char('L') => {
+                                let tx = tx.clone();
+                                app.open_log_viewer(tx).await?;
+                            }
+                            KeyCode::Tab => {
+                                app.next_tab();
+                            }
+                            KeyCode::BackTab => {
+                                app.previous_tab();
+                            }
+                            KeyCode::Char('n') => {
+                                app.open_tab();
+                            }
+                            KeyCode::Char('x') => {
+                                app.close_tab();
+                            }
+                            KeyCode::Char('m') | KeyCode::Right => {
+                                app.toggle_context_menu();
+                            }
+                            KeyCode::Char('P') => {
+                                app.toggle_preview_pane();
+                            }
+                            KeyCode::Char('[') => {
+                                app.preview_scroll_up();
+                            }
+                            KeyCode::Char(']') => {
+                                app.preview_scroll_down();
+                            }
+                            KeyCode::Char('/') => {
+                                app.enter_filter_mode();
+                            }
+                            KeyCode::Char('I') => {
+                                app.toggle_iac_popup();
+                            }
+                            KeyCode::Char('A') => {
+                                app.enter_aggregate_mode();
+                            }
+                            KeyCode::Char('N') => {
+                                app.write_ansible_inventory()?;
+                            }
+                            _ => {}
                         }
-                        _ => {}
                     }
                 }
             }
@@ -100,7 +273,15 @@ async fn run_app(
 
         // Handle async events
         while let Ok(event) = rx.try_recv() {
-            app.handle_event(event).await?;
+            app.handle_event(event, tx.clone()).await?;
+        }
+
+        app.reap_shell_if_exited();
+        app.prune_expired_operations();
+        app.advance_spinner();
+
+        if app.show_preview_pane {
+            app.ensure_preview_loaded();
         }
 
         if app.should_quit() {
@@ -108,3 +289,23 @@ async fn run_app(
         }
     }
 }
+
+/// Translate a crossterm key into the raw bytes a terminal program expects
+/// on stdin.
+fn key_event_to_pty_bytes(code: KeyCode) -> Option<Vec<u8>> {
+    match code {
+        KeyCode::Char(c) => {
+            let mut buf = [0u8; 4];
+            Some(c.encode_utf8(&mut buf).as_bytes().to_vec())
+        }
+        KeyCode::Enter => Some(b"\r".to_vec()),
+        KeyCode::Backspace => Some(vec![0x7f]),
+        KeyCode::Tab => Some(b"\t".to_vec()),
+        KeyCode::Esc => Some(vec![0x1b]),
+        KeyCode::Up => Some(b"\x1b[A".to_vec()),
+        KeyCode::Down => Some(b"\x1b[B".to_vec()),
+        KeyCode::Right => Some(b"\x1b[C".to_vec()),
+        KeyCode::Left => Some(b"\x1b[D".to_vec()),
+        _ => None,
+    }
+}
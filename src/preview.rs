@@ -0,0 +1,59 @@
+//! Syntax highlighting for the detail/preview pane.
+//!
+//! The preview pane shows the selected resource's JSON detail tokenized by
+//! `syntect` and converted span-by-span into ratatui `Style`s, the same
+//! "ansi-to-tui" shape `ui::draw_shell_pane` already uses for vt100 cells.
+//! Highlighting is best-effort: if a syntax/theme can't be loaded, or a line
+//! fails to tokenize, callers fall back to plain unstyled text rather than
+//! losing the content.
+
+use ratatui::style::{Color, Style};
+use syntect::easy::HighlightLines;
+use syntect::highlighting::{Style as SyntectStyle, ThemeSet};
+use syntect::parsing::SyntaxSet;
+use syntect::util::LinesWithEndings;
+
+/// One highlighted line: a sequence of (text, style) runs ready to become
+/// ratatui `Span`s.
+pub type HighlightedLine = Vec<(String, Style)>;
+
+/// Tokenize `json` with `syntect`'s bundled JSON syntax, falling back to
+/// unstyled lines if the syntax/theme can't be loaded or highlighting fails
+/// partway through.
+pub fn highlight_json(json: &str) -> Vec<HighlightedLine> {
+    highlight_json_inner(json).unwrap_or_else(|| plain_lines(json))
+}
+
+fn highlight_json_inner(json: &str) -> Option<Vec<HighlightedLine>> {
+    let syntax_set = SyntaxSet::load_defaults_newlines();
+    let syntax = syntax_set.find_syntax_by_extension("json")?;
+    let theme_set = ThemeSet::load_defaults();
+    let theme = theme_set.themes.get("base16-ocean.dark")?;
+    let mut highlighter = HighlightLines::new(syntax, theme);
+
+    let mut lines = Vec::new();
+    for line in LinesWithEndings::from(json) {
+        let ranges = highlighter.highlight_line(line, &syntax_set).ok()?;
+        lines.push(
+            ranges
+                .into_iter()
+                .map(|(style, text)| (text.trim_end_matches(['\n', '\r']).to_string(), to_ratatui_style(style)))
+                .collect(),
+        );
+    }
+    Some(lines)
+}
+
+fn to_ratatui_style(style: SyntectStyle) -> Style {
+    Style::default().fg(Color::Rgb(
+        style.foreground.r,
+        style.foreground.g,
+        style.foreground.b,
+    ))
+}
+
+fn plain_lines(json: &str) -> Vec<HighlightedLine> {
+    json.lines()
+        .map(|line| vec![(line.to_string(), Style::default())])
+        .collect()
+}
@@ -0,0 +1,125 @@
+//! Interactive shell sessions embedded directly in the TUI.
+//!
+//! Historically `start_ecs_exec`/`start_ssh_session` tore down raw mode and
+//! handed the terminal to a child process. This module instead opens a
+//! pseudo-terminal (via `portable-pty`), feeds it keystrokes from the normal
+//! crossterm event loop, and parses its output through a `vt100` terminal
+//! emulator so a ratatui widget can render the live screen without ever
+//! leaving the alternate screen.
+
+use anyhow::{Context, Result};
+use portable_pty::{native_pty_system, CommandBuilder, MasterPty, PtySize};
+use std::io::{Read, Write};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+pub struct PtySession {
+    pub title: String,
+    writer: Box<dyn Write + Send>,
+    parser: Arc<Mutex<vt100::Parser>>,
+    master: Box<dyn MasterPty + Send>,
+    child: Box<dyn portable_pty::Child + Send + Sync>,
+}
+
+impl PtySession {
+    /// Spawn `program` with `args` inside a new PTY of the given size.
+    pub fn spawn(
+        title: impl Into<String>,
+        program: &str,
+        args: &[String],
+        rows: u16,
+        cols: u16,
+    ) -> Result<Self> {
+        let pty_system = native_pty_system();
+        let pair = pty_system
+            .openpty(PtySize {
+                rows,
+                cols,
+                pixel_width: 0,
+                pixel_height: 0,
+            })
+            .context("Failed to allocate a PTY")?;
+
+        let mut cmd = CommandBuilder::new(program);
+        cmd.args(args);
+
+        let child = pair
+            .slave
+            .spawn_command(cmd)
+            .context("Failed to spawn command in PTY")?;
+        // The slave end belongs to the child now.
+        drop(pair.slave);
+
+        let mut reader = pair
+            .master
+            .try_clone_reader()
+            .context("Failed to clone PTY reader")?;
+        let writer = pair
+            .master
+            .take_writer()
+            .context("Failed to take PTY writer")?;
+
+        let parser = Arc::new(Mutex::new(vt100::Parser::new(rows, cols, 0)));
+        let parser_for_reader = parser.clone();
+
+        thread::spawn(move || {
+            let mut buf = [0u8; 4096];
+            loop {
+                match reader.read(&mut buf) {
+                    Ok(0) | Err(_) => break,
+                    Ok(n) => {
+                        if let Ok(mut parser) = parser_for_reader.lock() {
+                            parser.process(&buf[..n]);
+                        }
+                    }
+                }
+            }
+        });
+
+        Ok(Self {
+            title: title.into(),
+            writer,
+            parser,
+            master: pair.master,
+            child,
+        })
+    }
+
+    /// Forward raw keystroke bytes to the PTY master.
+    pub fn write_input(&mut self, bytes: &[u8]) -> Result<()> {
+        self.writer.write_all(bytes)?;
+        Ok(())
+    }
+
+    /// Resize both the PTY and the emulator so subsequent output reflows
+    /// against the new dimensions.
+    pub fn resize(&mut self, rows: u16, cols: u16) -> Result<()> {
+        self.master.resize(PtySize {
+            rows,
+            cols,
+            pixel_width: 0,
+            pixel_height: 0,
+        })?;
+        if let Ok(mut parser) = self.parser.lock() {
+            parser.set_size(rows, cols);
+        }
+        Ok(())
+    }
+
+    /// Snapshot of the emulator's current screen, for rendering.
+    pub fn screen(&self) -> vt100::Screen {
+        self.parser
+            .lock()
+            .map(|p| p.screen().clone())
+            .unwrap_or_else(|_| vt100::Parser::new(0, 0, 0).screen().clone())
+    }
+
+    /// Whether the child process is still running.
+    pub fn is_alive(&mut self) -> bool {
+        matches!(self.child.try_wait(), Ok(None))
+    }
+
+    pub fn kill(&mut self) {
+        let _ = self.child.kill();
+    }
+}
@@ -1,37 +1,23 @@
 use anyhow::{Context, Result};
+use std::io::Write;
 use std::process::{Command, Stdio};
 
-use crate::app::Ec2Instance;
+use crate::app::PortForwardSession;
 
-/// Start an ECS Exec session using the AWS CLI
+/// Open an SSM port-forwarding tunnel to an EC2 instance.
 ///
-/// This function spawns the AWS CLI command to start an interactive session
-/// with a container running in ECS using ECS Exec (which uses SSM Session Manager).
-///
-/// Prerequisites:
-/// - AWS CLI v2 must be installed
-/// - Session Manager plugin must be installed
-/// - The ECS task must have been started with enableExecuteCommand=true
-/// - The task role must have the necessary SSM permissions
-pub async fn start_ecs_exec(
+/// Unlike the embedded shell pane in [`crate::pty`], this does not block for
+/// the lifetime of the session: `aws ssm start-session
+/// --document-name AWS-StartPortForwardingSession` is spawned in the
+/// background and its [`std::process::Child`] handle is handed back so the
+/// caller can keep the TUI responsive while the tunnel stays open, and kill
+/// it later (on quit or cancellation).
+pub async fn start_port_forward_session(
     region: &str,
-    cluster_arn: &str,
-    task_arn: &str,
-    container_name: &str,
-) -> Result<()> {
-    // Extract cluster name from ARN
-    let cluster_name = cluster_arn
-        .split('/')
-        .last()
-        .context("Invalid cluster ARN")?;
-
-    // Extract task ID from ARN
-    let task_id = task_arn
-        .split('/')
-        .last()
-        .context("Invalid task ARN")?;
-
-    // Temporarily exit the TUI to run the interactive session
+    instance_id: &str,
+) -> Result<PortForwardSession> {
+    // Temporarily exit the TUI to prompt for the ports, same as the other
+    // session flows in this module.
     crossterm::terminal::disable_raw_mode()?;
     crossterm::execute!(
         std::io::stdout(),
@@ -39,50 +25,58 @@ pub async fn start_ecs_exec(
     )?;
 
     println!("\n╔════════════════════════════════════════════════════════════════╗");
-    println!("║          Starting ECS Exec Session                            ║");
+    println!("║          SSM Port Forwarding                                   ║");
     println!("╟────────────────────────────────────────────────────────────────╢");
+    println!("║ Instance:  {:<51} ║", instance_id);
     println!("║ Region:    {:<51} ║", region);
-    println!("║ Cluster:   {:<51} ║", cluster_name);
-    println!("║ Task:      {:<51} ║", task_id);
-    println!("║ Container: {:<51} ║", container_name);
-    println!("╟────────────────────────────────────────────────────────────────╢");
-    println!("║ Type 'exit' or press Ctrl+D to return to the console          ║");
     println!("╚════════════════════════════════════════════════════════════════╝\n");
 
-    // Build the AWS ECS execute-command
-    let status = Command::new("aws")
-        .arg("ecs")
-        .arg("execute-command")
-        .arg("--region")
-        .arg(region)
-        .arg("--cluster")
-        .arg(cluster_name)
-        .arg("--task")
-        .arg(task_id)
-        .arg("--container")
-        .arg(container_name)
-        .arg("--interactive")
-        .arg("--command")
-        .arg("/bin/sh")
-        .stdin(Stdio::inherit())
-        .stdout(Stdio::inherit())
-        .stderr(Stdio::inherit())
-        .status()
-        .context("Failed to execute AWS CLI command. Make sure AWS CLI v2 and Session Manager plugin are installed.")?;
-
-    if !status.success() {
-        eprintln!("\n❌ ECS Exec session failed with status: {}", status);
-        eprintln!("\nCommon issues:");
-        eprintln!("  1. The task was not started with enableExecuteCommand=true");
-        eprintln!("  2. The task role lacks necessary SSM permissions");
-        eprintln!("  3. Session Manager plugin is not installed");
-        eprintln!("  4. The container is not running or doesn't have /bin/sh");
-        eprintln!("\nFor more details, visit:");
-        eprintln!("  https://docs.aws.amazon.com/AmazonECS/latest/developerguide/ecs-exec.html\n");
+    print!("Remote port (on the instance): ");
+    std::io::stdout().flush()?;
+    let mut remote_port_input = String::new();
+    std::io::stdin().read_line(&mut remote_port_input)?;
+    let remote_port: u16 = remote_port_input
+        .trim()
+        .parse()
+        .context("Invalid remote port")?;
+
+    print!("Local port [{}]: ", remote_port);
+    std::io::stdout().flush()?;
+    let mut local_port_input = String::new();
+    std::io::stdin().read_line(&mut local_port_input)?;
+    let local_port_input = local_port_input.trim();
+    let local_port: u16 = if local_port_input.is_empty() {
+        remote_port
     } else {
-        println!("\n✓ ECS Exec session ended successfully\n");
-    }
+        local_port_input.parse().context("Invalid local port")?
+    };
 
+    let params = format!(
+        "portNumber={},localPortNumber={}",
+        remote_port, local_port
+    );
+
+    let child = Command::new("aws")
+        .arg("ssm")
+        .arg("start-session")
+        .arg("--region")
+        .arg(region)
+        .arg("--target")
+        .arg(instance_id)
+        .arg("--document-name")
+        .arg("AWS-StartPortForwardingSession")
+        .arg("--parameters")
+        .arg(params)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .context("Failed to start AWS SSM port forwarding session. Make sure AWS CLI v2 and Session Manager plugin are installed.")?;
+
+    println!(
+        "\n✓ Tunnel started: localhost:{} -> {}:{} (running in the background)\n",
+        local_port, instance_id, remote_port
+    );
     println!("Press Enter to return to the console...");
     let mut input = String::new();
     std::io::stdin().read_line(&mut input)?;
@@ -94,172 +88,60 @@ pub async fn start_ecs_exec(
         crossterm::terminal::EnterAlternateScreen
     )?;
 
-    Ok(())
+    Ok(PortForwardSession {
+        instance_id: instance_id.to_string(),
+        remote_port,
+        local_port,
+        child,
+    })
 }
 
-/// Check if ECS Exec is enabled for a task
-///
-/// This function can be used to verify if a task has ECS Exec enabled
-/// before attempting to start a session.
-#[allow(dead_code)]
-pub async fn check_exec_enabled(
-    region: &str,
-    cluster_name: &str,
-    task_id: &str,
-) -> Result<bool> {
-    let output = Command::new("aws")
-        .arg("ecs")
-        .arg("describe-tasks")
-        .arg("--region")
-        .arg(region)
-        .arg("--cluster")
-        .arg(cluster_name)
-        .arg("--tasks")
-        .arg(task_id)
-        .arg("--query")
-        .arg("tasks[0].enableExecuteCommand")
-        .arg("--output")
-        .arg("text")
-        .output()
-        .context("Failed to check if ECS Exec is enabled")?;
-
-    let result = String::from_utf8(output.stdout)?;
-    Ok(result.trim() == "True")
-}
-
-/// Start an SSH session to an EC2 instance
-///
-/// This function spawns an SSH command to connect to an EC2 instance.
-/// It will attempt to use AWS SSM Session Manager first (recommended),
-/// and fall back to traditional SSH if SSM is not available.
-///
-/// Prerequisites:
-/// - For SSM: AWS CLI v2 and Session Manager plugin must be installed
-/// - For SSH: SSH client must be installed and SSH key must be configured
-pub async fn start_ssh_session(instance: &Ec2Instance) -> Result<()> {
-    // Temporarily exit the TUI to run the interactive session
+/// Prompt for the command (and optional target user) to run in an ECS Exec
+/// session, pre-filled with the environment's configured defaults so
+/// accepting both prompts with Enter keeps those defaults (with none
+/// configured beyond the default command, that is `/bin/sh` as the
+/// container's default user).
+pub fn prompt_exec_options(
+    default_command: &str,
+    default_user: Option<&str>,
+) -> Result<(String, Option<String>)> {
     crossterm::terminal::disable_raw_mode()?;
     crossterm::execute!(
         std::io::stdout(),
         crossterm::terminal::LeaveAlternateScreen
     )?;
 
-    println!("\n╔════════════════════════════════════════════════════════════════╗");
-    println!("║          EC2 SSH Connection                                    ║");
-    println!("╟────────────────────────────────────────────────────────────────╢");
-    println!("║ Instance:  {:<51} ║", instance.instance_id);
-    println!("║ Name:      {:<51} ║", instance.name);
-    println!("║ State:     {:<51} ║", instance.state);
-    if let Some(public_ip) = &instance.public_ip {
-        println!("║ Public IP: {:<51} ║", public_ip);
-    }
-    if let Some(private_ip) = &instance.private_ip {
-        println!("║ Private IP:{:<51} ║", private_ip);
-    }
-    println!("╟────────────────────────────────────────────────────────────────╢");
-    println!("║ Choose connection method:                                      ║");
-    println!("║   1) AWS Systems Manager (SSM) - Recommended                   ║");
-    println!("║   2) Traditional SSH                                           ║");
-    println!("║   3) Cancel                                                    ║");
-    println!("╚════════════════════════════════════════════════════════════════╝\n");
-
-    print!("Enter choice (1-3): ");
-    use std::io::Write;
+    print!("Command to run [{}]: ", default_command);
     std::io::stdout().flush()?;
+    let mut command_input = String::new();
+    std::io::stdin().read_line(&mut command_input)?;
+    let command = command_input.trim();
+    let command = if command.is_empty() {
+        default_command.to_string()
+    } else {
+        command.to_string()
+    };
+
+    let user_prompt = match default_user {
+        Some(user) => format!("Run as user [{}]: ", user),
+        None => "Run as user (blank for root): ".to_string(),
+    };
+    print!("{}", user_prompt);
+    std::io::stdout().flush()?;
+    let mut user_input = String::new();
+    std::io::stdin().read_line(&mut user_input)?;
+    let user_input = user_input.trim();
+    let user = if user_input.is_empty() {
+        default_user.map(|u| u.to_string())
+    } else {
+        Some(user_input.to_string())
+    };
 
-    let mut choice = String::new();
-    std::io::stdin().read_line(&mut choice)?;
-
-    let choice = choice.trim();
-
-    match choice {
-        "1" => {
-            println!("\nStarting SSM session...\n");
-            let status = Command::new("aws")
-                .arg("ssm")
-                .arg("start-session")
-                .arg("--target")
-                .arg(&instance.instance_id)
-                .stdin(Stdio::inherit())
-                .stdout(Stdio::inherit())
-                .stderr(Stdio::inherit())
-                .status()
-                .context("Failed to execute AWS SSM command. Make sure AWS CLI v2 and Session Manager plugin are installed.")?;
-
-            if !status.success() {
-                eprintln!("\n❌ SSM session failed with status: {}", status);
-                eprintln!("\nCommon issues:");
-                eprintln!("  1. Session Manager plugin is not installed");
-                eprintln!("  2. Instance doesn't have SSM agent installed/running");
-                eprintln!("  3. Instance role lacks necessary SSM permissions");
-                eprintln!("  4. Security group/network doesn't allow SSM connection");
-                eprintln!("\nFor more details, visit:");
-                eprintln!("  https://docs.aws.amazon.com/systems-manager/latest/userguide/session-manager.html\n");
-            } else {
-                println!("\n✓ SSM session ended successfully\n");
-            }
-        }
-        "2" => {
-            if instance.state != "running" {
-                eprintln!("\n❌ Instance is not in running state (current: {})", instance.state);
-            } else if let Some(ip) = instance.public_ip.as_ref().or(instance.private_ip.as_ref()) {
-                println!("\n╔════════════════════════════════════════════════════════════════╗");
-                println!("║ SSH Connection Options                                        ║");
-                println!("╟────────────────────────────────────────────────────────────────╢");
-                println!("║ Enter SSH username (e.g., ec2-user, ubuntu, admin):           ║");
-                println!("╚════════════════════════════════════════════════════════════════╝\n");
-
-                print!("Username [ec2-user]: ");
-                std::io::stdout().flush()?;
-
-                let mut username = String::new();
-                std::io::stdin().read_line(&mut username)?;
-                let username = username.trim();
-                let username = if username.is_empty() { "ec2-user" } else { username };
-
-                println!("\nConnecting via SSH to {}@{}...\n", username, ip);
-                println!("Note: You may need to specify your SSH key with -i flag if the default key doesn't work.\n");
-
-                let status = Command::new("ssh")
-                    .arg(format!("{}@{}", username, ip))
-                    .stdin(Stdio::inherit())
-                    .stdout(Stdio::inherit())
-                    .stderr(Stdio::inherit())
-                    .status()
-                    .context("Failed to execute SSH command. Make sure SSH client is installed.")?;
-
-                if !status.success() {
-                    eprintln!("\n❌ SSH connection failed");
-                    eprintln!("\nTroubleshooting:");
-                    eprintln!("  1. Ensure you have the correct SSH key");
-                    eprintln!("  2. Try: ssh -i /path/to/key.pem {}@{}", username, ip);
-                    eprintln!("  3. Check security group allows SSH (port 22)");
-                    eprintln!("  4. Verify network connectivity\n");
-                } else {
-                    println!("\n✓ SSH session ended successfully\n");
-                }
-            } else {
-                eprintln!("\n❌ No IP address available for this instance\n");
-            }
-        }
-        "3" => {
-            println!("\nConnection cancelled.\n");
-        }
-        _ => {
-            eprintln!("\nInvalid choice. Connection cancelled.\n");
-        }
-    }
-
-    println!("Press Enter to return to the console...");
-    let mut input = String::new();
-    std::io::stdin().read_line(&mut input)?;
-
-    // Re-enter the TUI
     crossterm::terminal::enable_raw_mode()?;
     crossterm::execute!(
         std::io::stdout(),
         crossterm::terminal::EnterAlternateScreen
     )?;
 
-    Ok(())
+    Ok((command, user))
 }
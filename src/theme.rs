@@ -0,0 +1,217 @@
+//! Configurable color theme for the TUI, loaded from
+//! `~/.config/ncaws/theme.toml` and layered over a built-in default. Every
+//! `draw_*` function in `ui.rs` should read its styles from `app.theme`
+//! rather than hard-coding `Color::*`, so operators can match the tool to
+//! their terminal palette without recompiling. Respects `NO_COLOR`
+//! (https://no-color.org) by collapsing every style to the terminal's own
+//! default.
+
+use anyhow::{Context, Result};
+use ratatui::style::{Color, Modifier, Style};
+use serde::Deserialize;
+use std::path::PathBuf;
+
+/// A single style, every field of which may be left unset in a user's
+/// config so only the parts being overridden need to be mentioned.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct OwnedStyleSpec {
+    pub fg: Option<String>,
+    pub bg: Option<String>,
+    #[serde(default)]
+    pub bold: bool,
+}
+
+impl OwnedStyleSpec {
+    fn or(self, fallback: OwnedStyleSpec) -> OwnedStyleSpec {
+        OwnedStyleSpec {
+            fg: self.fg.or(fallback.fg),
+            bg: self.bg.or(fallback.bg),
+            bold: self.bold || fallback.bold,
+        }
+    }
+
+    fn to_style(&self) -> Style {
+        let mut style = Style::default();
+        if let Some(fg) = self.fg.as_deref().and_then(parse_color) {
+            style = style.fg(fg);
+        }
+        if let Some(bg) = self.bg.as_deref().and_then(parse_color) {
+            style = style.bg(bg);
+        }
+        if self.bold {
+            style = style.add_modifier(Modifier::BOLD);
+        }
+        style
+    }
+}
+
+fn parse_color(name: &str) -> Option<Color> {
+    match name.to_ascii_lowercase().as_str() {
+        "black" => Some(Color::Black),
+        "red" => Some(Color::Red),
+        "green" => Some(Color::Green),
+        "yellow" => Some(Color::Yellow),
+        "blue" => Some(Color::Blue),
+        "magenta" => Some(Color::Magenta),
+        "cyan" => Some(Color::Cyan),
+        "white" => Some(Color::White),
+        "gray" | "grey" => Some(Color::Gray),
+        "darkgray" | "darkgrey" => Some(Color::DarkGray),
+        "reset" => Some(Color::Reset),
+        _ => None,
+    }
+}
+
+/// The raw, partially-specified theme as loaded from TOML. Field names
+/// match the semantic roles the renderers ask for (`selection`,
+/// `status_good`, ...) rather than literal color names, so a remapped
+/// palette doesn't need to rename anything.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ThemeSpec {
+    #[serde(default)]
+    pub selection: OwnedStyleSpec,
+    #[serde(default)]
+    pub header_border: OwnedStyleSpec,
+    /// Neutral body text, e.g. the footer's base style.
+    #[serde(default)]
+    pub text: OwnedStyleSpec,
+    #[serde(default)]
+    pub hint: OwnedStyleSpec,
+    #[serde(default)]
+    pub action: OwnedStyleSpec,
+    #[serde(default)]
+    pub empty_message: OwnedStyleSpec,
+    #[serde(default)]
+    pub error: OwnedStyleSpec,
+    /// ACTIVE / RUNNING / available
+    #[serde(default)]
+    pub status_good: OwnedStyleSpec,
+    /// PENDING / DRAINING / modifying
+    #[serde(default)]
+    pub status_warn: OwnedStyleSpec,
+    /// STOPPED / FAILED
+    #[serde(default)]
+    pub status_bad: OwnedStyleSpec,
+    /// terminated / unknown
+    #[serde(default)]
+    pub status_neutral: OwnedStyleSpec,
+}
+
+impl ThemeSpec {
+    /// Layer `self` (e.g. a user's file) over `fallback` (the built-in
+    /// default), field by field: `self.field.or(fallback.field)`.
+    fn merged_over(self, fallback: ThemeSpec) -> ThemeSpec {
+        ThemeSpec {
+            selection: self.selection.or(fallback.selection),
+            header_border: self.header_border.or(fallback.header_border),
+            text: self.text.or(fallback.text),
+            hint: self.hint.or(fallback.hint),
+            action: self.action.or(fallback.action),
+            empty_message: self.empty_message.or(fallback.empty_message),
+            error: self.error.or(fallback.error),
+            status_good: self.status_good.or(fallback.status_good),
+            status_warn: self.status_warn.or(fallback.status_warn),
+            status_bad: self.status_bad.or(fallback.status_bad),
+            status_neutral: self.status_neutral.or(fallback.status_neutral),
+        }
+    }
+}
+
+fn default_spec() -> ThemeSpec {
+    let fg = |name: &str| OwnedStyleSpec {
+        fg: Some(name.to_string()),
+        bg: None,
+        bold: false,
+    };
+
+    ThemeSpec {
+        selection: OwnedStyleSpec {
+            fg: Some("black".to_string()),
+            bg: Some("cyan".to_string()),
+            bold: true,
+        },
+        header_border: fg("cyan"),
+        text: fg("white"),
+        hint: fg("yellow"),
+        action: fg("green"),
+        empty_message: fg("yellow"),
+        error: OwnedStyleSpec {
+            fg: Some("red".to_string()),
+            bg: None,
+            bold: true,
+        },
+        status_good: fg("green"),
+        status_warn: fg("yellow"),
+        status_bad: fg("red"),
+        status_neutral: fg("gray"),
+    }
+}
+
+/// Resolved styles ready to hand straight to ratatui widgets, built once at
+/// startup by [`Theme::load`] instead of re-parsing color names every
+/// frame.
+#[derive(Debug, Clone, Copy)]
+pub struct Theme {
+    pub selection: Style,
+    pub header_border: Style,
+    pub text: Style,
+    pub hint: Style,
+    pub action: Style,
+    pub empty_message: Style,
+    pub error: Style,
+    pub status_good: Style,
+    pub status_warn: Style,
+    pub status_bad: Style,
+    pub status_neutral: Style,
+}
+
+impl Theme {
+    /// Load `~/.config/ncaws/theme.toml` (if present) merged over the
+    /// built-in default, or fall back to the default unconfigured. Every
+    /// style collapses to the terminal's own default when `NO_COLOR` is set.
+    pub fn load() -> Result<Self> {
+        if std::env::var_os("NO_COLOR").is_some() {
+            return Ok(Self::from_spec(&ThemeSpec::default()));
+        }
+
+        let user_spec = Self::load_user_spec()?.unwrap_or_default();
+        Ok(Self::from_spec(&user_spec.merged_over(default_spec())))
+    }
+
+    fn load_user_spec() -> Result<Option<ThemeSpec>> {
+        let path = Self::path()?;
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let contents = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        toml::from_str(&contents)
+            .with_context(|| format!("Failed to parse {}", path.display()))
+            .map(Some)
+    }
+
+    fn path() -> Result<PathBuf> {
+        let home = std::env::var("HOME").context("HOME is not set")?;
+        Ok(PathBuf::from(home)
+            .join(".config")
+            .join("ncaws")
+            .join("theme.toml"))
+    }
+
+    fn from_spec(spec: &ThemeSpec) -> Self {
+        Self {
+            selection: spec.selection.to_style(),
+            header_border: spec.header_border.to_style(),
+            text: spec.text.to_style(),
+            hint: spec.hint.to_style(),
+            action: spec.action.to_style(),
+            empty_message: spec.empty_message.to_style(),
+            error: spec.error.to_style(),
+            status_good: spec.status_good.to_style(),
+            status_warn: spec.status_warn.to_style(),
+            status_bad: spec.status_bad.to_style(),
+            status_neutral: spec.status_neutral.to_style(),
+        }
+    }
+}
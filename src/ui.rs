@@ -2,41 +2,73 @@ use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, List, ListItem, Paragraph},
+    widgets::{Block, Borders, List, ListItem, Paragraph, Tabs},
     Frame,
 };
 
-use crate::app::{App, NavigationLevel, ServiceType};
+use crate::app::{App, IacFormat, NavigationLevel, OperationState, RolloutState, ServiceType};
 
 pub fn draw(f: &mut Frame, app: &App) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(3),  // Header
+            Constraint::Length(3),  // Tab strip
             Constraint::Min(0),     // Main content
             Constraint::Length(3),  // Footer
         ])
         .split(f.size());
 
     draw_header(f, app, chunks[0]);
-    draw_main_content(f, app, chunks[1]);
-    draw_footer(f, app, chunks[2]);
+    draw_tab_strip(f, app, chunks[1]);
+    draw_main_content(f, app, chunks[2]);
+    draw_footer(f, app, chunks[3]);
 
     // Draw info popup on top if enabled
     if app.show_info_popup {
         draw_info_popup(f, app);
     }
+
+    if app.show_iac_popup {
+        draw_iac_popup(f, app);
+    }
+
+    if app.show_port_forward_panel {
+        draw_port_forward_panel(f, app);
+    }
+
+    if app.show_log_popup {
+        draw_log_popup(f, app);
+    }
+
+    if app.show_context_menu {
+        draw_context_menu(f, app);
+    }
 }
 
 fn draw_header(f: &mut Frame, app: &App, area: Rect) {
     let breadcrumb = build_breadcrumb(app);
     let title = Paragraph::new(breadcrumb)
         .block(Block::default().borders(Borders::ALL).title(" AWS ECS Console "))
-        .style(Style::default().fg(Color::Cyan));
+        .style(app.theme.header_border);
 
     f.render_widget(title, area);
 }
 
+fn draw_tab_strip(f: &mut Frame, app: &App, area: Rect) {
+    let titles: Vec<Line> = app.tabs.titles.iter().map(|t| Line::from(t.clone())).collect();
+    let tabs = Tabs::new(titles)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(" Tabs (Tab/Shift-Tab cycle, n: new, x: close) "),
+        )
+        .select(app.tabs.index)
+        .highlight_style(app.theme.selection);
+
+    f.render_widget(tabs, area);
+}
+
 fn build_breadcrumb(app: &App) -> String {
     let mut parts = vec![];
 
@@ -132,55 +164,221 @@ fn build_breadcrumb(app: &App) -> String {
 }
 
 fn draw_main_content(f: &mut Frame, app: &App, area: Rect) {
-    if app.loading {
-        let msg = Paragraph::new("Loading...")
-            .block(Block::default().borders(Borders::ALL))
-            .style(Style::default().fg(Color::Yellow));
-        f.render_widget(msg, area);
+    if let Some(shell) = &app.active_shell {
+        draw_shell_pane(f, shell, area);
         return;
     }
 
+    let area = if app.filter_mode || !app.filter_query.is_empty() {
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(3), Constraint::Min(0)])
+            .split(area);
+        draw_filter_bar(f, app, rows[0]);
+        rows[1]
+    } else {
+        area
+    };
+
+    let list_area = if app.show_preview_pane {
+        let panes = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(area);
+        draw_preview_pane(f, app, panes[1]);
+        panes[0]
+    } else {
+        area
+    };
+
     match app.navigation.level {
-        NavigationLevel::Region => draw_region_list(f, app, area),
-        NavigationLevel::ServiceType => draw_service_type_list(f, app, area),
-        NavigationLevel::Cluster => draw_cluster_list(f, app, area),
-        NavigationLevel::Service => draw_service_list(f, app, area),
-        NavigationLevel::Task => draw_task_list(f, app, area),
-        NavigationLevel::Container => draw_container_list(f, app, area),
-        NavigationLevel::Ec2Instance => draw_ec2_instance_list(f, app, area),
-        NavigationLevel::RdsCluster => draw_rds_cluster_list(f, app, area),
-        NavigationLevel::RdsInstance => draw_rds_instance_list(f, app, area),
+        NavigationLevel::Region => draw_region_list(f, app, list_area),
+        NavigationLevel::ServiceType => draw_service_type_list(f, app, list_area),
+        NavigationLevel::Cluster => draw_cluster_list(f, app, list_area),
+        NavigationLevel::Service => draw_service_list(f, app, list_area),
+        NavigationLevel::Task => draw_task_list(f, app, list_area),
+        NavigationLevel::Container => draw_container_list(f, app, list_area),
+        NavigationLevel::Ec2Instance => draw_ec2_instance_list(f, app, list_area),
+        NavigationLevel::RdsCluster => draw_rds_cluster_list(f, app, list_area),
+        NavigationLevel::RdsInstance => draw_rds_instance_list(f, app, list_area),
+    }
+}
+
+/// The input line shown above the list while a fuzzy filter ('/') is being
+/// typed or applied.
+fn draw_filter_bar(f: &mut Frame, app: &App, area: Rect) {
+    let cursor = if app.filter_mode { "_" } else { "" };
+    let bar = Paragraph::new(format!("/{}{}", app.filter_query, cursor))
+        .block(Block::default().borders(Borders::ALL).title(" Filter (Esc to clear) "))
+        .style(app.theme.hint);
+
+    f.render_widget(bar, area);
+}
+
+/// Split `label` into per-character `Span`s, bolding whichever characters
+/// matched the active fuzzy filter. Falls back to a single unstyled span
+/// when there's no match to highlight, so the common unfiltered case stays
+/// cheap.
+fn filtered_label_spans(app: &App, label: &str, style: Style) -> Vec<Span<'static>> {
+    let matched = app.filter_match_indices(label);
+    if matched.is_empty() {
+        return vec![Span::styled(label.to_string(), style)];
+    }
+
+    label
+        .chars()
+        .enumerate()
+        .map(|(i, ch)| {
+            let char_style = if matched.contains(&i) {
+                style.add_modifier(Modifier::BOLD)
+            } else {
+                style
+            };
+            Span::styled(ch.to_string(), char_style)
+        })
+        .collect()
+}
+
+/// The right-hand pane toggled by 'P', showing the selected resource's
+/// syntax-highlighted JSON detail - tags, environment, network config, IAM
+/// role - next to its list instead of in a separate popup.
+fn draw_preview_pane(f: &mut Frame, app: &App, area: Rect) {
+    let title = " Preview ([/] scroll, P to close) ";
+
+    let Some(lines) = app.preview_lines() else {
+        let msg = Paragraph::new("Select a cluster, service, task, container, or instance to preview it")
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .style(app.theme.empty_message);
+        f.render_widget(msg, area);
+        return;
+    };
+
+    let rendered: Vec<Line> = lines
+        .iter()
+        .map(|spans| {
+            Line::from(
+                spans
+                    .iter()
+                    .map(|(text, style)| Span::styled(text.clone(), *style))
+                    .collect::<Vec<_>>(),
+            )
+        })
+        .collect();
+
+    let paragraph = Paragraph::new(rendered)
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .scroll((app.preview_scroll_offset as u16, 0));
+
+    f.render_widget(paragraph, area);
+}
+
+fn draw_shell_pane(f: &mut Frame, shell: &crate::pty::PtySession, area: Rect) {
+    let screen = shell.screen();
+    let (rows, cols) = screen.size();
+
+    let lines: Vec<Line> = (0..rows)
+        .map(|row| {
+            let spans: Vec<Span> = (0..cols)
+                .map(|col| {
+                    let cell = screen.cell(row, col);
+                    let contents = cell.map(|c| c.contents()).unwrap_or_default();
+                    let contents = if contents.is_empty() {
+                        " ".to_string()
+                    } else {
+                        contents
+                    };
+
+                    let mut style = Style::default();
+                    if let Some(cell) = cell {
+                        style = style.fg(vt100_color(cell.fgcolor()));
+                        style = style.bg(vt100_color(cell.bgcolor()));
+                        if cell.bold() {
+                            style = style.add_modifier(Modifier::BOLD);
+                        }
+                        if cell.underline() {
+                            style = style.add_modifier(Modifier::UNDERLINED);
+                        }
+                        if cell.italic() {
+                            style = style.add_modifier(Modifier::ITALIC);
+                        }
+                    }
+
+                    Span::styled(contents, style)
+                })
+                .collect();
+
+            Line::from(spans)
+        })
+        .collect();
+
+    let title = format!(" {} (F10 to close) ", shell.title);
+    let paragraph = Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title(title));
+
+    f.render_widget(paragraph, area);
+}
+
+fn vt100_color(color: vt100::Color) -> Color {
+    match color {
+        vt100::Color::Default => Color::Reset,
+        vt100::Color::Idx(i) => Color::Indexed(i),
+        vt100::Color::Rgb(r, g, b) => Color::Rgb(r, g, b),
     }
 }
 
+/// Render a security group's IP permissions as a compact one-line summary,
+/// e.g. `tcp/22 from 0.0.0.0/0, tcp/443 from 10.0.0.0/8`.
+fn format_ip_permissions(perms: &[crate::app::IpPermissionInfo]) -> String {
+    if perms.is_empty() {
+        return "none".to_string();
+    }
+
+    perms
+        .iter()
+        .map(|perm| {
+            let ports = match (perm.from_port, perm.to_port) {
+                (Some(from), Some(to)) if from == to => format!("{}/{}", perm.protocol, from),
+                (Some(from), Some(to)) => format!("{}/{}-{}", perm.protocol, from, to),
+                _ => format!("{}/all", perm.protocol),
+            };
+            let cidrs = if perm.cidrs.is_empty() {
+                "no CIDRs".to_string()
+            } else {
+                perm.cidrs.join(", ")
+            };
+            format!("{} from {}", ports, cidrs)
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 fn draw_region_list(f: &mut Frame, app: &App, area: Rect) {
     let items: Vec<ListItem> = app
-        .regions
-        .iter()
-        .enumerate()
+        .filtered_indices()
+        .into_iter()
+        .filter_map(|i| app.regions.get(i).map(|region| (i, region)))
         .map(|(i, region)| {
             let style = if i == app.selected_index {
-                Style::default()
-                    .fg(Color::Black)
-                    .bg(Color::Cyan)
-                    .add_modifier(Modifier::BOLD)
+                app.theme.selection
             } else {
-                Style::default().fg(Color::White)
+                Style::default()
             };
 
-            let content = Line::from(vec![
-                Span::styled("  ", style),
-                Span::styled(&region.name, style),
-            ]);
+            let mut spans = vec![Span::styled("  ", style)];
+            spans.extend(filtered_label_spans(app, &region.name, style));
+            if let Some(partition) = &region.partition {
+                if partition != "aws" {
+                    spans.push(Span::styled(format!(" ({})", partition), style));
+                }
+            }
 
-            ListItem::new(content)
+            ListItem::new(Line::from(spans))
         })
         .collect();
 
     let list = List::new(items).block(
         Block::default()
             .borders(Borders::ALL)
-            .title(" Regions (↑/↓ to navigate, Enter to select) "),
+            .title(" Regions (↑/↓ to navigate, Enter to select, / to filter) "),
     );
 
     f.render_widget(list, area);
@@ -193,12 +391,9 @@ fn draw_service_type_list(f: &mut Frame, app: &App, area: Rect) {
         .enumerate()
         .map(|(i, service_type)| {
             let style = if i == app.selected_index {
-                Style::default()
-                    .fg(Color::Black)
-                    .bg(Color::Cyan)
-                    .add_modifier(Modifier::BOLD)
+                app.theme.selection
             } else {
-                Style::default().fg(Color::White)
+                Style::default()
             };
 
             let (name, icon) = match service_type {
@@ -231,39 +426,38 @@ fn draw_cluster_list(f: &mut Frame, app: &App, area: Rect) {
     if app.clusters.is_empty() {
         let msg = Paragraph::new("No clusters found in this region")
             .block(Block::default().borders(Borders::ALL).title(" Clusters "))
-            .style(Style::default().fg(Color::Yellow));
+            .style(app.theme.empty_message);
         f.render_widget(msg, area);
         return;
     }
 
     let items: Vec<ListItem> = app
-        .clusters
-        .iter()
-        .enumerate()
+        .filtered_indices()
+        .into_iter()
+        .filter_map(|i| app.clusters.get(i).map(|cluster| (i, cluster)))
         .map(|(i, cluster)| {
             let style = if i == app.selected_index {
-                Style::default()
-                    .fg(Color::Black)
-                    .bg(Color::Cyan)
-                    .add_modifier(Modifier::BOLD)
+                app.theme.selection
             } else {
-                Style::default().fg(Color::White)
+                Style::default()
             };
 
-            let content = Line::from(vec![
-                Span::styled("  ", style),
-                Span::styled(&cluster.name, style),
-            ]);
+            let mut spans = vec![Span::styled("  ", style)];
+            spans.extend(filtered_label_spans(app, &cluster.name, style));
+            if app.navigation.aggregate_regions {
+                spans.push(Span::styled(format!(" [{}]", cluster.region), app.theme.hint));
+            }
 
-            ListItem::new(content)
+            ListItem::new(Line::from(spans))
         })
         .collect();
 
-    let list = List::new(items).block(
-        Block::default()
-            .borders(Borders::ALL)
-            .title(" Clusters (↑/↓ to navigate, Enter to select, Esc to go back) "),
-    );
+    let title = if app.navigation.aggregate_regions {
+        " Clusters - all regions (↑/↓ to navigate, Enter to select, Esc to go back, / to filter) "
+    } else {
+        " Clusters (↑/↓ to navigate, Enter to select, Esc to go back, / to filter) "
+    };
+    let list = List::new(items).block(Block::default().borders(Borders::ALL).title(title));
 
     f.render_widget(list, area);
 }
@@ -272,36 +466,33 @@ fn draw_service_list(f: &mut Frame, app: &App, area: Rect) {
     if app.services.is_empty() {
         let msg = Paragraph::new("No services found in this cluster")
             .block(Block::default().borders(Borders::ALL).title(" Services "))
-            .style(Style::default().fg(Color::Yellow));
+            .style(app.theme.empty_message);
         f.render_widget(msg, area);
         return;
     }
 
     let items: Vec<ListItem> = app
-        .services
-        .iter()
-        .enumerate()
+        .filtered_indices()
+        .into_iter()
+        .filter_map(|i| app.services.get(i).map(|service| (i, service)))
         .map(|(i, service)| {
             let style = if i == app.selected_index {
-                Style::default()
-                    .fg(Color::Black)
-                    .bg(Color::Cyan)
-                    .add_modifier(Modifier::BOLD)
+                app.theme.selection
             } else {
-                Style::default().fg(Color::White)
+                Style::default()
             };
 
-            let status_color = match service.status.as_str() {
-                "ACTIVE" => Color::Green,
-                "DRAINING" => Color::Yellow,
-                _ => Color::Red,
+            let status_style = match service.status.as_str() {
+                "ACTIVE" => app.theme.status_good,
+                "DRAINING" => app.theme.status_warn,
+                _ => app.theme.status_bad,
             };
 
-            let content = Line::from(vec![
-                Span::styled("  ", style),
-                Span::styled(&service.name, style),
+            let mut spans = vec![Span::styled("  ", style)];
+            spans.extend(filtered_label_spans(app, &service.name, style));
+            spans.extend(vec![
                 Span::styled(" [", style),
-                Span::styled(&service.status, Style::default().fg(status_color).bg(if i == app.selected_index { Color::Cyan } else { Color::Reset })),
+                Span::styled(&service.status, status_style.bg(if i == app.selected_index { app.theme.selection.bg.unwrap_or(Color::Reset) } else { Color::Reset })),
                 Span::styled("] ", style),
                 Span::styled(
                     format!("{}/{} tasks", service.running_count, service.desired_count),
@@ -309,6 +500,26 @@ fn draw_service_list(f: &mut Frame, app: &App, area: Rect) {
                 ),
             ]);
 
+            if let Some(rollout) = &app.navigation.active_rollout {
+                if rollout.service_name == service.name {
+                    let (label, rollout_style) = match rollout.status.rollout_state {
+                        RolloutState::InProgress => ("rolling out", app.theme.status_warn),
+                        RolloutState::Completed => ("rollout complete", app.theme.status_good),
+                        RolloutState::Failed => ("rollout failed", app.theme.status_bad),
+                        RolloutState::RolledBack => ("rolled back", app.theme.status_bad),
+                    };
+                    spans.push(Span::styled(
+                        format!(
+                            " [{} {}/{} running, {} pending]",
+                            label, rollout.status.running, rollout.status.desired, rollout.status.pending
+                        ),
+                        rollout_style,
+                    ));
+                }
+            }
+
+            let content = Line::from(spans);
+
             ListItem::new(content)
         })
         .collect();
@@ -316,7 +527,7 @@ fn draw_service_list(f: &mut Frame, app: &App, area: Rect) {
     let list = List::new(items).block(
         Block::default()
             .borders(Borders::ALL)
-            .title(" Services (↑/↓ to navigate, Enter to select, Esc to go back) "),
+            .title(" Services (↑/↓ to navigate, Enter to select, Esc to go back, / to filter) "),
     );
 
     f.render_widget(list, area);
@@ -326,51 +537,48 @@ fn draw_task_list(f: &mut Frame, app: &App, area: Rect) {
     if app.tasks.is_empty() {
         let msg = Paragraph::new("No tasks found for this service")
             .block(Block::default().borders(Borders::ALL).title(" Tasks "))
-            .style(Style::default().fg(Color::Yellow));
+            .style(app.theme.empty_message);
         f.render_widget(msg, area);
         return;
     }
 
     let items: Vec<ListItem> = app
-        .tasks
-        .iter()
-        .enumerate()
+        .filtered_indices()
+        .into_iter()
+        .filter_map(|i| app.tasks.get(i).map(|task| (i, task)))
         .map(|(i, task)| {
             let style = if i == app.selected_index {
-                Style::default()
-                    .fg(Color::Black)
-                    .bg(Color::Cyan)
-                    .add_modifier(Modifier::BOLD)
+                app.theme.selection
             } else {
-                Style::default().fg(Color::White)
+                Style::default()
             };
 
-            let status_color = match task.status.as_str() {
-                "RUNNING" => Color::Green,
-                "PENDING" => Color::Yellow,
-                "STOPPED" => Color::Red,
-                _ => Color::Gray,
+            let status_style = match task.status.as_str() {
+                "RUNNING" => app.theme.status_good,
+                "PENDING" => app.theme.status_warn,
+                "STOPPED" => app.theme.status_bad,
+                _ => app.theme.status_neutral,
             };
 
-            let content = Line::from(vec![
-                Span::styled("  ", style),
-                Span::styled(&task.task_id, style),
+            let mut spans = vec![Span::styled("  ", style)];
+            spans.extend(filtered_label_spans(app, &task.task_id, style));
+            spans.extend(vec![
                 Span::styled(" [", style),
-                Span::styled(&task.status, Style::default().fg(status_color).bg(if i == app.selected_index { Color::Cyan } else { Color::Reset })),
+                Span::styled(&task.status, status_style.bg(if i == app.selected_index { app.theme.selection.bg.unwrap_or(Color::Reset) } else { Color::Reset })),
                 Span::styled("] CPU: ", style),
                 Span::styled(&task.cpu, style),
                 Span::styled(" MEM: ", style),
                 Span::styled(&task.memory, style),
             ]);
 
-            ListItem::new(content)
+            ListItem::new(Line::from(spans))
         })
         .collect();
 
     let list = List::new(items).block(
         Block::default()
             .borders(Borders::ALL)
-            .title(" Tasks (↑/↓ to navigate, Enter to select, Esc to go back) "),
+            .title(" Tasks (↑/↓ to navigate, Enter to select, Esc to go back, / to filter) "),
     );
 
     f.render_widget(list, area);
@@ -380,40 +588,39 @@ fn draw_container_list(f: &mut Frame, app: &App, area: Rect) {
     if app.containers.is_empty() {
         let msg = Paragraph::new("No containers found for this task")
             .block(Block::default().borders(Borders::ALL).title(" Containers "))
-            .style(Style::default().fg(Color::Yellow));
+            .style(app.theme.empty_message);
         f.render_widget(msg, area);
         return;
     }
 
     let items: Vec<ListItem> = app
-        .containers
-        .iter()
-        .enumerate()
+        .filtered_indices()
+        .into_iter()
+        .filter_map(|i| app.containers.get(i).map(|container| (i, container)))
         .map(|(i, container)| {
             let style = if i == app.selected_index {
-                Style::default()
-                    .fg(Color::Black)
-                    .bg(Color::Cyan)
-                    .add_modifier(Modifier::BOLD)
+                app.theme.selection
             } else {
-                Style::default().fg(Color::White)
+                Style::default()
             };
 
-            let status_color = match container.status.as_str() {
-                "RUNNING" => Color::Green,
-                "PENDING" => Color::Yellow,
-                "STOPPED" => Color::Red,
-                _ => Color::Gray,
+            let status_style = match container.status.as_str() {
+                "RUNNING" => app.theme.status_good,
+                "PENDING" => app.theme.status_warn,
+                "STOPPED" => app.theme.status_bad,
+                _ => app.theme.status_neutral,
             };
 
+            let mut name_spans = vec![Span::styled("  ", style)];
+            name_spans.extend(filtered_label_spans(app, &container.name, style.add_modifier(Modifier::BOLD)));
+            name_spans.extend(vec![
+                Span::styled(" [", style),
+                Span::styled(&container.status, status_style.bg(if i == app.selected_index { app.theme.selection.bg.unwrap_or(Color::Reset) } else { Color::Reset })),
+                Span::styled("]", style),
+            ]);
+
             let lines = vec![
-                Line::from(vec![
-                    Span::styled("  ", style),
-                    Span::styled(&container.name, style.add_modifier(Modifier::BOLD)),
-                    Span::styled(" [", style),
-                    Span::styled(&container.status, Style::default().fg(status_color).bg(if i == app.selected_index { Color::Cyan } else { Color::Reset })),
-                    Span::styled("]", style),
-                ]),
+                Line::from(name_spans),
                 Line::from(vec![
                     Span::styled("    Image: ", style),
                     Span::styled(&container.image, style),
@@ -427,7 +634,7 @@ fn draw_container_list(f: &mut Frame, app: &App, area: Rect) {
     let list = List::new(items).block(
         Block::default()
             .borders(Borders::ALL)
-            .title(" Containers (↑/↓ to navigate, 'e' to exec, Esc to go back) "),
+            .title(" Containers (↑/↓ to navigate, 'e' to exec, Esc to go back, / to filter) "),
     );
 
     f.render_widget(list, area);
@@ -437,39 +644,34 @@ fn draw_ec2_instance_list(f: &mut Frame, app: &App, area: Rect) {
     if app.ec2_instances.is_empty() {
         let msg = Paragraph::new("No EC2 instances found in this region")
             .block(Block::default().borders(Borders::ALL).title(" EC2 Instances "))
-            .style(Style::default().fg(Color::Yellow));
+            .style(app.theme.empty_message);
         f.render_widget(msg, area);
         return;
     }
 
     let items: Vec<ListItem> = app
-        .ec2_instances
-        .iter()
-        .enumerate()
+        .filtered_indices()
+        .into_iter()
+        .filter_map(|i| app.ec2_instances.get(i).map(|instance| (i, instance)))
         .map(|(i, instance)| {
             let style = if i == app.selected_index {
-                Style::default()
-                    .fg(Color::Black)
-                    .bg(Color::Cyan)
-                    .add_modifier(Modifier::BOLD)
+                app.theme.selection
             } else {
-                Style::default().fg(Color::White)
+                Style::default()
             };
 
-            let state_color = match instance.state.as_str() {
-                "running" => Color::Green,
-                "stopped" => Color::Red,
-                "pending" => Color::Yellow,
-                "stopping" => Color::Yellow,
-                "terminated" => Color::DarkGray,
-                _ => Color::Gray,
+            let state_style = match instance.state.as_str() {
+                "running" => app.theme.status_good,
+                "stopped" => app.theme.status_bad,
+                "pending" | "stopping" => app.theme.status_warn,
+                _ => app.theme.status_neutral,
             };
 
             let mut line2_spans = vec![
                 Span::styled("    Type: ", style),
                 Span::styled(instance.instance_type.clone(), style),
                 Span::styled(" | State: ", style),
-                Span::styled(instance.state.clone(), Style::default().fg(state_color).bg(if i == app.selected_index { Color::Cyan } else { Color::Reset })),
+                Span::styled(instance.state.clone(), state_style.bg(if i == app.selected_index { app.theme.selection.bg.unwrap_or(Color::Reset) } else { Color::Reset })),
                 Span::styled(" | ", style),
             ];
 
@@ -484,14 +686,19 @@ fn draw_ec2_instance_list(f: &mut Frame, app: &App, area: Rect) {
                 line2_spans.push(Span::styled("No IP".to_string(), style));
             }
 
+            let mut name_spans = vec![Span::styled("  ", style)];
+            name_spans.extend(filtered_label_spans(app, &instance.name, style.add_modifier(Modifier::BOLD)));
+            name_spans.extend(vec![
+                Span::styled(" (", style),
+                Span::styled(instance.instance_id.clone(), style),
+                Span::styled(")", style),
+            ]);
+            if app.navigation.aggregate_regions {
+                name_spans.push(Span::styled(format!(" [{}]", instance.region), app.theme.hint));
+            }
+
             let lines = vec![
-                Line::from(vec![
-                    Span::styled("  ", style),
-                    Span::styled(instance.name.clone(), style.add_modifier(Modifier::BOLD)),
-                    Span::styled(" (", style),
-                    Span::styled(instance.instance_id.clone(), style),
-                    Span::styled(")", style),
-                ]),
+                Line::from(name_spans),
                 Line::from(line2_spans),
             ];
 
@@ -499,11 +706,12 @@ fn draw_ec2_instance_list(f: &mut Frame, app: &App, area: Rect) {
         })
         .collect();
 
-    let list = List::new(items).block(
-        Block::default()
-            .borders(Borders::ALL)
-            .title(" EC2 Instances (↑/↓ to navigate, 's' to SSH, Esc to go back) "),
-    );
+    let title = if app.navigation.aggregate_regions {
+        " EC2 Instances - all regions (↑/↓ to navigate, 's' to SSH, Esc to go back, / to filter) "
+    } else {
+        " EC2 Instances (↑/↓ to navigate, 's' to SSH, Esc to go back, / to filter) "
+    };
+    let list = List::new(items).block(Block::default().borders(Borders::ALL).title(title));
 
     f.render_widget(list, area);
 }
@@ -512,7 +720,7 @@ fn draw_rds_cluster_list(f: &mut Frame, app: &App, area: Rect) {
     if app.rds_clusters.is_empty() {
         let msg = Paragraph::new("No RDS clusters found in this region")
             .block(Block::default().borders(Borders::ALL).title(" RDS Clusters "))
-            .style(Style::default().fg(Color::Yellow));
+            .style(app.theme.empty_message);
         f.render_widget(msg, area);
         return;
     }
@@ -523,19 +731,16 @@ fn draw_rds_cluster_list(f: &mut Frame, app: &App, area: Rect) {
         .enumerate()
         .map(|(i, cluster)| {
             let style = if i == app.selected_index {
-                Style::default()
-                    .fg(Color::Black)
-                    .bg(Color::Cyan)
-                    .add_modifier(Modifier::BOLD)
+                app.theme.selection
             } else {
-                Style::default().fg(Color::White)
+                Style::default()
             };
 
-            let status_color = match cluster.status.as_str() {
-                "available" => Color::Green,
-                "creating" | "modifying" | "backing-up" => Color::Yellow,
-                "stopped" | "stopping" => Color::Red,
-                _ => Color::Gray,
+            let status_style = match cluster.status.as_str() {
+                "available" => app.theme.status_good,
+                "creating" | "modifying" | "backing-up" => app.theme.status_warn,
+                "stopped" | "stopping" => app.theme.status_bad,
+                _ => app.theme.status_neutral,
             };
 
             let endpoint_display = cluster.endpoint
@@ -548,7 +753,7 @@ fn draw_rds_cluster_list(f: &mut Frame, app: &App, area: Rect) {
                     Span::styled("  ", style),
                     Span::styled(&cluster.identifier, style.add_modifier(Modifier::BOLD)),
                     Span::styled(" [", style),
-                    Span::styled(&cluster.status, Style::default().fg(status_color).bg(if i == app.selected_index { Color::Cyan } else { Color::Reset })),
+                    Span::styled(&cluster.status, status_style.bg(if i == app.selected_index { app.theme.selection.bg.unwrap_or(Color::Reset) } else { Color::Reset })),
                     Span::styled("]", style),
                 ]),
                 Line::from(vec![
@@ -577,7 +782,7 @@ fn draw_rds_instance_list(f: &mut Frame, app: &App, area: Rect) {
     if app.rds_instances.is_empty() {
         let msg = Paragraph::new("No RDS instances found in this cluster")
             .block(Block::default().borders(Borders::ALL).title(" RDS Instances "))
-            .style(Style::default().fg(Color::Yellow));
+            .style(app.theme.empty_message);
         f.render_widget(msg, area);
         return;
     }
@@ -588,19 +793,16 @@ fn draw_rds_instance_list(f: &mut Frame, app: &App, area: Rect) {
         .enumerate()
         .map(|(i, instance)| {
             let style = if i == app.selected_index {
-                Style::default()
-                    .fg(Color::Black)
-                    .bg(Color::Cyan)
-                    .add_modifier(Modifier::BOLD)
+                app.theme.selection
             } else {
-                Style::default().fg(Color::White)
+                Style::default()
             };
 
-            let status_color = match instance.status.as_str() {
-                "available" => Color::Green,
-                "creating" | "modifying" | "backing-up" | "rebooting" => Color::Yellow,
-                "stopped" | "stopping" | "failed" => Color::Red,
-                _ => Color::Gray,
+            let status_style = match instance.status.as_str() {
+                "available" => app.theme.status_good,
+                "creating" | "modifying" | "backing-up" | "rebooting" => app.theme.status_warn,
+                "stopped" | "stopping" | "failed" => app.theme.status_bad,
+                _ => app.theme.status_neutral,
             };
 
             let endpoint_display = instance.endpoint
@@ -613,7 +815,7 @@ fn draw_rds_instance_list(f: &mut Frame, app: &App, area: Rect) {
                     Span::styled("  ", style),
                     Span::styled(&instance.identifier, style.add_modifier(Modifier::BOLD)),
                     Span::styled(" [", style),
-                    Span::styled(&instance.status, Style::default().fg(status_color).bg(if i == app.selected_index { Color::Cyan } else { Color::Reset })),
+                    Span::styled(&instance.status, status_style.bg(if i == app.selected_index { app.theme.selection.bg.unwrap_or(Color::Reset) } else { Color::Reset })),
                     Span::styled("]", style),
                 ]),
                 Line::from(vec![
@@ -641,30 +843,106 @@ fn draw_rds_instance_list(f: &mut Frame, app: &App, area: Rect) {
 }
 
 fn draw_footer(f: &mut Frame, app: &App, area: Rect) {
+    if app.active_shell.is_some() {
+        let footer = Paragraph::new(Line::from(vec![
+            Span::raw(" Keystrokes go to the shell | "),
+            Span::styled("F10: close", app.theme.hint),
+        ]))
+        .block(Block::default().borders(Borders::ALL))
+        .style(app.theme.text);
+        f.render_widget(footer, area);
+        return;
+    }
+
     let mut footer_text = vec![
         Span::raw(" q: quit | "),
         Span::raw("↑/↓: navigate | "),
         Span::raw("Enter: select | "),
         Span::raw("Esc: back | "),
         Span::raw("r: refresh | "),
-        Span::styled("i: info", Style::default().fg(Color::Yellow)),
+        Span::styled("i: info", app.theme.hint),
+        Span::raw(" | "),
+        Span::styled("I: export IaC", app.theme.hint),
     ];
 
+    if app.navigation.level == NavigationLevel::Region {
+        footer_text.push(Span::raw(" | "));
+        footer_text.push(Span::styled("A: all regions", app.theme.action));
+    }
+
     if app.navigation.level == NavigationLevel::Service {
         footer_text.push(Span::raw(" | "));
-        footer_text.push(Span::styled("f: deploy", Style::default().fg(Color::Green)));
+        footer_text.push(Span::styled("f: deploy", app.theme.action));
     }
 
     if app.navigation.level == NavigationLevel::Container {
         footer_text.push(Span::raw(" | "));
-        footer_text.push(Span::styled("e: exec", Style::default().fg(Color::Green)));
+        footer_text.push(Span::styled("e: exec", app.theme.action));
+        footer_text.push(Span::raw(" | "));
+        footer_text.push(Span::styled("L: logs", app.theme.action));
     }
 
     if app.navigation.level == NavigationLevel::Ec2Instance {
         footer_text.push(Span::raw(" | "));
-        footer_text.push(Span::styled("s: SSH", Style::default().fg(Color::Green)));
+        footer_text.push(Span::styled("s: SSH", app.theme.action));
+        footer_text.push(Span::raw(" | "));
+        footer_text.push(Span::styled("p: port-forward", app.theme.action));
+        footer_text.push(Span::raw(" | "));
+        footer_text.push(Span::styled("N: Ansible inventory", app.theme.action));
+    }
+
+    if matches!(
+        app.navigation.level,
+        NavigationLevel::Container | NavigationLevel::Ec2Instance | NavigationLevel::Service
+    ) {
+        footer_text.push(Span::raw(" | "));
+        footer_text.push(Span::styled("m: actions", app.theme.action));
+    }
+
+    footer_text.push(Span::raw(" | "));
+    footer_text.push(Span::styled(
+        if app.show_preview_pane {
+            "P: preview (on)".to_string()
+        } else {
+            "P: preview".to_string()
+        },
+        app.theme.hint,
+    ));
+
+    if app.navigation.level != NavigationLevel::ServiceType {
+        footer_text.push(Span::raw(" | "));
+        footer_text.push(Span::styled(
+            if app.filter_query.is_empty() {
+                "/: filter".to_string()
+            } else {
+                format!("/: filter ({})", app.filter_query)
+            },
+            app.theme.hint,
+        ));
+    }
+
+    footer_text.push(Span::raw(" | "));
+    footer_text.push(Span::styled("t: tunnels", app.theme.hint));
+
+    footer_text.push(Span::raw(" | "));
+    match &app.current_aws_profile {
+        Some(profile) => footer_text.push(Span::styled(
+            format!("a: profile ({})", profile),
+            app.theme.hint,
+        )),
+        None => footer_text.push(Span::styled("a: profile", app.theme.hint)),
     }
 
+    footer_text.push(Span::raw(" | "));
+    footer_text.push(Span::styled(
+        if app.auto_refresh_enabled {
+            "l: live (on)".to_string()
+        } else {
+            "l: live (paused)".to_string()
+        },
+        app.theme.hint,
+    ));
+
     footer_text.push(Span::raw(" | "));
     footer_text.push(Span::raw(&app.status_message));
 
@@ -672,17 +950,44 @@ fn draw_footer(f: &mut Frame, app: &App, area: Rect) {
         footer_text.push(Span::raw(" | "));
         footer_text.push(Span::styled(
             format!("ERROR: {}", error),
-            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            app.theme.error,
         ));
     }
 
+    if !app.operations.is_empty() {
+        footer_text.push(Span::raw(" | "));
+        footer_text.extend(operation_spans(app));
+    }
+
     let footer = Paragraph::new(Line::from(footer_text))
         .block(Block::default().borders(Borders::ALL))
-        .style(Style::default().fg(Color::White));
+        .style(app.theme.text);
 
     f.render_widget(footer, area);
 }
 
+/// Render every tracked background operation as "<spinner> name…" (or,
+/// once it's failed, "name: error" in the error style), joined by " | ".
+fn operation_spans(app: &App) -> Vec<Span<'static>> {
+    let mut spans = Vec::new();
+    for (i, op) in app.operations.iter().enumerate() {
+        if i > 0 {
+            spans.push(Span::raw(" | "));
+        }
+        match &op.state {
+            OperationState::InProgress => spans.push(Span::styled(
+                format!("{} {}…", app.spinner_char(), op.name),
+                app.theme.hint,
+            )),
+            OperationState::Failed(error) => spans.push(Span::styled(
+                format!("{}: {}", op.name, error),
+                app.theme.error,
+            )),
+        }
+    }
+    spans
+}
+
 fn draw_info_popup(f: &mut Frame, app: &App) {
     let area = f.size();
 
@@ -706,18 +1011,192 @@ fn draw_info_popup(f: &mut Frame, app: &App) {
 
     let info_text = get_info_text(app);
 
+    // The expanded EC2 panel (block devices, ENIs, security groups) can run
+    // longer than the popup is tall, so clamp the scroll offset to the
+    // content rather than letting it scroll past into blank space.
+    let line_count = info_text.lines().count() as u16;
+    let visible_height = popup_area.height.saturating_sub(2);
+    let max_scroll = line_count.saturating_sub(visible_height);
+    let scroll = app.info_popup_scroll.min(max_scroll);
+
     let paragraph = Paragraph::new(info_text)
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .title(" Info (Press 'i' or 'Esc' to close) ")
-                .style(Style::default().fg(Color::Cyan))
+                .title(" Info (↑/↓/PgUp/PgDn to scroll, 'i' or Esc to close) ")
+                .style(app.theme.header_border)
+        )
+        .wrap(ratatui::widgets::Wrap { trim: true })
+        .scroll((scroll, 0));
+
+    f.render_widget(paragraph, popup_area);
+}
+
+fn draw_iac_popup(f: &mut Frame, app: &App) {
+    let area = f.size();
+
+    // Centered popup (80% width, 80% height), same sizing as the info popup.
+    let popup_width = (area.width * 80) / 100;
+    let popup_height = (area.height * 80) / 100;
+    let popup_x = (area.width - popup_width) / 2;
+    let popup_y = (area.height - popup_height) / 2;
+
+    let popup_area = Rect {
+        x: popup_x,
+        y: popup_y,
+        width: popup_width,
+        height: popup_height,
+    };
+
+    let clear_block = Block::default().style(Style::default().bg(Color::Reset));
+    f.render_widget(clear_block, popup_area);
+
+    let format_name = match app.iac_format {
+        IacFormat::Terraform => "Terraform",
+        IacFormat::CloudFormation => "CloudFormation",
+    };
+
+    let paragraph = Paragraph::new(app.iac_popup_text())
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!(
+                    " Export as {} ('c' to switch format, 'w' to write, 'I'/Esc to close) ",
+                    format_name
+                ))
+                .style(app.theme.header_border),
         )
         .wrap(ratatui::widgets::Wrap { trim: true });
 
     f.render_widget(paragraph, popup_area);
 }
 
+fn draw_port_forward_panel(f: &mut Frame, app: &App) {
+    let area = f.size();
+
+    let popup_width = (area.width * 60) / 100;
+    let popup_height = (area.height * 50) / 100;
+    let popup_x = (area.width - popup_width) / 2;
+    let popup_y = (area.height - popup_height) / 2;
+
+    let popup_area = Rect {
+        x: popup_x,
+        y: popup_y,
+        width: popup_width,
+        height: popup_height,
+    };
+
+    let clear_block = Block::default().style(Style::default().bg(Color::Reset));
+    f.render_widget(clear_block, popup_area);
+
+    let items: Vec<ListItem> = if app.port_forward_sessions.is_empty() {
+        vec![ListItem::new("No active tunnels. Press 'p' on an EC2 instance to open one.")]
+    } else {
+        app.port_forward_sessions
+            .iter()
+            .map(|session| {
+                ListItem::new(Line::from(format!(
+                    "localhost:{} -> {}:{}",
+                    session.local_port, session.instance_id, session.remote_port
+                )))
+            })
+            .collect()
+    };
+
+    let list = List::new(items).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(" Port Forwarding Tunnels ('t' to close) ")
+            .style(app.theme.header_border),
+    );
+
+    f.render_widget(list, popup_area);
+}
+
+fn draw_context_menu(f: &mut Frame, app: &App) {
+    let area = f.size();
+
+    let popup_width = (area.width * 40) / 100;
+    let popup_height = (app.context_menu_actions.len() as u16 + 2).min(area.height);
+    let popup_x = (area.width - popup_width) / 2;
+    let popup_y = (area.height - popup_height) / 2;
+
+    let popup_area = Rect {
+        x: popup_x,
+        y: popup_y,
+        width: popup_width,
+        height: popup_height,
+    };
+
+    let clear_block = Block::default().style(Style::default().bg(Color::Reset));
+    f.render_widget(clear_block, popup_area);
+
+    let items: Vec<ListItem> = app
+        .context_menu_actions
+        .iter()
+        .enumerate()
+        .map(|(i, action)| {
+            let style = if i == app.context_menu_index {
+                app.theme.selection
+            } else {
+                Style::default()
+            };
+            ListItem::new(Line::from(Span::styled(action.label(), style)))
+        })
+        .collect();
+
+    let list = List::new(items).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(" Actions (↑/↓, Enter, Esc) ")
+            .style(app.theme.header_border),
+    );
+
+    f.render_widget(list, popup_area);
+}
+
+fn draw_log_popup(f: &mut Frame, app: &App) {
+    let area = f.size();
+
+    let popup_width = (area.width * 90) / 100;
+    let popup_height = (area.height * 80) / 100;
+    let popup_x = (area.width - popup_width) / 2;
+    let popup_y = (area.height - popup_height) / 2;
+
+    let popup_area = Rect {
+        x: popup_x,
+        y: popup_y,
+        width: popup_width,
+        height: popup_height,
+    };
+
+    let clear_block = Block::default().style(Style::default().bg(Color::Reset));
+    f.render_widget(clear_block, popup_area);
+
+    // Inner height (minus the two border rows) decides how many lines fit;
+    // scroll so `log_scroll_offset` is the last visible line.
+    let visible_rows = popup_area.height.saturating_sub(2) as usize;
+    let end = (app.log_scroll_offset + 1).min(app.log_lines.len());
+    let start = end.saturating_sub(visible_rows);
+
+    let text = app.log_lines[start..end].join("\n");
+
+    let follow_label = if app.log_follow { "following" } else { "paused" };
+    let paragraph = Paragraph::new(text)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!(
+                    " Logs ({}) - j/k scroll, f toggle follow, L/Esc close ",
+                    follow_label
+                ))
+                .style(app.theme.header_border),
+        )
+        .wrap(ratatui::widgets::Wrap { trim: false });
+
+    f.render_widget(paragraph, popup_area);
+}
+
 fn get_info_text(app: &App) -> String {
     match app.navigation.level {
         NavigationLevel::Region => {
@@ -776,10 +1255,12 @@ fn get_info_text(app: &App) -> String {
                 format!(
                     "ECS Cluster Information\n\
                     ═══════════════════════\n\n\
-                    Name: {}\n\n\
+                    Name: {}\n\
+                    Region: {}\n\n\
                     ARN:\n{}\n\
                     ",
                     cluster.name,
+                    cluster.region,
                     cluster.arn
                 )
             } else {
@@ -880,6 +1361,73 @@ fn get_info_text(app: &App) -> String {
                     .map(|arn| format!("IAM Instance Profile:\n{}\n", arn))
                     .unwrap_or_else(|| "IAM Instance Profile: None\n".to_string());
 
+                let security_groups = if instance.security_groups.is_empty() {
+                    "None\n".to_string()
+                } else {
+                    instance
+                        .security_groups
+                        .iter()
+                        .map(|sg| {
+                            format!(
+                                "{} ({}){}\n  in:  {}\n  out: {}\n",
+                                sg.group_name,
+                                sg.group_id,
+                                sg.vpc_id
+                                    .as_ref()
+                                    .map(|vpc| format!(" [{}]", vpc))
+                                    .unwrap_or_default(),
+                                format_ip_permissions(&sg.inbound),
+                                format_ip_permissions(&sg.outbound),
+                            )
+                        })
+                        .collect::<String>()
+                };
+
+                let block_devices = if instance.block_devices.is_empty() {
+                    "None\n".to_string()
+                } else {
+                    instance
+                        .block_devices
+                        .iter()
+                        .map(|d| {
+                            format!(
+                                "{} -> {} ({} GB, {}, delete-on-termination: {}, encrypted: {})\n",
+                                d.device_name,
+                                d.volume_id,
+                                d.size_gb,
+                                d.volume_type,
+                                d.delete_on_termination,
+                                d.encrypted,
+                            )
+                        })
+                        .collect::<String>()
+                };
+
+                let network_interfaces = if instance.network_interfaces.is_empty() {
+                    "None\n".to_string()
+                } else {
+                    instance
+                        .network_interfaces
+                        .iter()
+                        .map(|eni| {
+                            format!(
+                                "[{}] {} subnet: {} private: {} public: {} mac: {} sgs: {}\n",
+                                eni.device_index,
+                                eni.network_interface_id,
+                                eni.subnet_id.as_deref().unwrap_or("N/A"),
+                                eni.private_ip.as_deref().unwrap_or("N/A"),
+                                eni.public_ip.as_deref().unwrap_or("None"),
+                                eni.mac_address.as_deref().unwrap_or("N/A"),
+                                if eni.security_group_ids.is_empty() {
+                                    "none".to_string()
+                                } else {
+                                    eni.security_group_ids.join(", ")
+                                },
+                            )
+                        })
+                        .collect::<String>()
+                };
+
                 format!(
                     "EC2 Instance Information\n\
                     ════════════════════════\n\n\
@@ -887,23 +1435,34 @@ fn get_info_text(app: &App) -> String {
                     Instance ID: {}\n\
                     Type: {}\n\
                     State: {}\n\
+                    Region: {}\n\
                     Availability Zone: {}\n\
                     {}\n\
                     Network:\n\
                     {}{}\n\
                     Access:\n\
                     {}{}\n\
+                    Security Groups:\n\
+                    {}\n\
+                    Block Devices:\n\
+                    {}\n\
+                    Network Interfaces:\n\
+                    {}\
                     ",
                     instance.name,
                     instance.instance_id,
                     instance.instance_type,
                     instance.state,
+                    instance.region,
                     instance.availability_zone,
                     iam_profile,
                     public_ip,
                     private_ip,
                     key_name,
-                    ssm_status
+                    ssm_status,
+                    security_groups,
+                    block_devices,
+                    network_interfaces
                 )
             } else {
                 "No instance selected".to_string()